@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use crate::romanization::{RomanizationError, Scheme};
+
+/// ## Args
+/// Session options given on the command line
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    pub scheme: Scheme,
+    /// Deck to open instead of the default one
+    pub deck: Option<String>,
+    /// Directory of the decks instead of `$XDG_DATA_HOME/langlog`
+    pub data_dir: Option<PathBuf>,
+}
+impl Args {
+    pub const USAGE: &str = "Usage: langlog [--scheme rr|mr|yale] \
+                             [--deck NAME] [--data-dir DIR]";
+
+    pub fn parse(args: impl IntoIterator<Item = String>) -> ArgsResult<Self> {
+        let mut ret = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--scheme" => {
+                    ret.scheme = Self::value(&arg, args.next())?.parse()?
+                }
+                "--deck" => ret.deck = Some(Self::value(&arg, args.next())?),
+                "--data-dir" => {
+                    ret.data_dir = Some(Self::value(&arg, args.next())?.into())
+                }
+                _ => return Err(ArgsError::Unknown(arg)),
+            }
+        }
+        Ok(ret)
+    }
+
+    fn value(flag: &str, value: Option<String>) -> ArgsResult<String> {
+        value.ok_or_else(|| ArgsError::MissingValue(flag.into()))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ArgsError {
+    #[error("Unknown argument: {0}")]
+    Unknown(String),
+    #[error("Missing value for {0}")]
+    MissingValue(String),
+    #[error(transparent)]
+    Romanization(#[from] RomanizationError),
+}
+pub type ArgsResult<T> = Result<T, ArgsError>;
@@ -0,0 +1,121 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+/// Backups kept of each log, the oldest is dropped first
+pub const KEEP: usize = 5;
+/// Next to the logs
+const DIR: &str = "backups";
+
+/// ## Backup
+/// An earlier copy of a log file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backup {
+    pub path: PathBuf,
+    /// Seconds since the Unix epoch, when it was taken
+    pub taken: u64,
+}
+
+/// Backups of the log at `path`, newest first
+pub fn list(path: &Path) -> Vec<Backup> {
+    (1..=KEEP)
+        .filter_map(|n| {
+            let path = numbered(path, n);
+            let taken = fs::metadata(&path)
+                .and_then(|m| m.modified())
+                .ok()?
+                .duration_since(UNIX_EPOCH)
+                .ok()?
+                .as_secs();
+            Some(Backup { path, taken })
+        })
+        .collect()
+}
+
+/// Copies the log at `path` to its newest backup, the others moving
+/// down and the oldest dropped. Nothing is done if the log is missing or
+/// unchanged since the newest backup.
+pub fn rotate(path: &Path) -> io::Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+    let newest = numbered(path, 1);
+    if fs::read(&newest).ok() == Some(fs::read(path)?) {
+        return Ok(());
+    }
+    fs::create_dir_all(path.with_file_name(DIR))?;
+    for n in (1..KEEP).rev() {
+        let from = numbered(path, n);
+        if from.exists() {
+            fs::rename(from, numbered(path, n + 1))?;
+        }
+    }
+    fs::copy(path, newest)?;
+    Ok(())
+}
+
+/// Moves the backups of the log at `from` to go with `to`
+pub fn rename(from: &Path, to: &Path) -> io::Result<()> {
+    for n in 1..=KEEP {
+        let backup = numbered(from, n);
+        if backup.exists() {
+            fs::rename(backup, numbered(to, n))?;
+        }
+    }
+    Ok(())
+}
+
+/// Deletes the backups of the log at `path`
+pub fn delete(path: &Path) -> io::Result<()> {
+    for n in 1..=KEEP {
+        let backup = numbered(path, n);
+        if backup.exists() {
+            fs::remove_file(backup)?;
+        }
+    }
+    Ok(())
+}
+
+/// `backups/<file name>.<n>` next to the log at `path`
+fn numbered(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}", n));
+    path.with_file_name(DIR).join(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use crate::backup::{KEEP, delete, list, rename, rotate};
+
+    #[test]
+    fn test_rotate() {
+        let dir = env::temp_dir().join(format!("langlog-b-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let log = dir.join("deck.csv");
+        rotate(&log).unwrap();
+        assert!(list(&log).is_empty());
+
+        for i in 0..KEEP + 2 {
+            fs::write(&log, i.to_string()).unwrap();
+            rotate(&log).unwrap();
+            rotate(&log).unwrap();
+        }
+        let backups = list(&log);
+        assert_eq!(backups.len(), KEEP);
+        let read = |i: usize| fs::read_to_string(&backups[i].path).unwrap();
+        assert_eq!(read(0), (KEEP + 1).to_string());
+        assert_eq!(read(KEEP - 1), 2.to_string());
+
+        let moved = dir.join("moved.csv");
+        rename(&log, &moved).unwrap();
+        assert!(list(&log).is_empty());
+        assert_eq!(list(&moved).len(), KEEP);
+        delete(&moved).unwrap();
+        assert!(list(&moved).is_empty());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
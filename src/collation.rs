@@ -0,0 +1,138 @@
+use crate::{
+    jamo::{InitialJamo, Jamo},
+    syllable::Syllable,
+    text::{Segment, Text},
+};
+
+/// Keys of Hangul start here, after most other characters
+const HANGUL: u32 = 0xAC00;
+/// As in North Korean dictionaries a silent `ㅇ` comes last, after the
+/// doubled consonants, where it sounds [ŋ] only as a final
+const NORTH_INITIALS: &str = "ㄱㄴㄷㄹㅁㅂㅅㅈㅊㅋㅌㅍㅎㄲㄸㅃㅆㅉㅇ";
+const NORTH_MEDIALS: &str = "ㅏㅑㅓㅕㅗㅛㅜㅠㅡㅣㅐㅒㅔㅖㅚㅟㅢㅘㅝㅙㅞ";
+const NORTH_FINALS: &str =
+    "ㄱㄳㄴㄵㄶㄷㄹㄺㄻㄼㄽㄾㄿㅀㅁㅂㅄㅅㅇㅈㅊㅋㅌㅍㅎㄲㅆ";
+
+/// ## Collation
+/// Order Korean words are sorted in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Collation {
+    /// Code points of the syllables, the order of KS X 1026 and Unicode
+    #[default]
+    Unicode,
+    /// Letter by letter, finals read as the consonants they are made of,
+    /// e.g. `각` before `가게` and `값` between `갑` and `갓`
+    Jamo,
+    /// North Korean order, doubled consonants after `ㅎ` and a silent `ㅇ`
+    /// last
+    NorthKorean,
+}
+impl Collation {
+    pub fn all() -> [Collation; 3] {
+        [Collation::Unicode, Collation::Jamo, Collation::NorthKorean]
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Collation::Unicode => "Unicode",
+            Collation::Jamo => "jamo",
+            Collation::NorthKorean => "North Korean",
+        }
+    }
+
+    /// Sort key of `text` in this order
+    pub fn key(self, text: &Text) -> Vec<u32> {
+        let mut ret = vec![];
+        for segment in text.iter() {
+            match segment {
+                Segment::Hangul(h) => {
+                    for s in h.iter() {
+                        self.push_key(s, &mut ret);
+                    }
+                }
+                Segment::Literal(l) => ret.extend(l.chars().map(u32::from)),
+            }
+        }
+        ret
+    }
+
+    fn push_key(self, syllable: &Syllable, key: &mut Vec<u32>) {
+        match self {
+            Collation::Unicode => key.push(char::from(syllable).into()),
+            Collation::Jamo => {
+                let consonant = |i: InitialJamo| HANGUL + i.id() as u32;
+                key.extend(syllable.initial().map(consonant));
+                if let Some(m) = syllable.medial() {
+                    let (first, second) = m.components();
+                    key.extend(
+                        [Some(first), second]
+                            .into_iter()
+                            .flatten()
+                            .map(|m| HANGUL + 19 + m.id() as u32),
+                    );
+                }
+                if let Some(f) = syllable.finale() {
+                    let (first, second) = f.components();
+                    key.extend(
+                        [Some(first), second]
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|f| InitialJamo::try_from(f).ok())
+                            .map(consonant),
+                    );
+                }
+            }
+            Collation::NorthKorean => {
+                let initial =
+                    syllable.initial().map_or(0, |i| rank(NORTH_INITIALS, i));
+                let medial =
+                    syllable.medial().map_or(0, |m| rank(NORTH_MEDIALS, m) + 1);
+                let finale =
+                    syllable.finale().map_or(0, |f| rank(NORTH_FINALS, f) + 1);
+                key.push(HANGUL + (initial * 22 + medial) * 28 + finale);
+            }
+        }
+    }
+}
+
+/// Position of `jamo` in `order`
+fn rank(order: &str, jamo: impl Into<Jamo>) -> u32 {
+    let c = char::from(jamo.into());
+    order.chars().position(|o| o == c).expect("Logic error!") as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{collation::Collation, text::Text};
+
+    fn sort(collation: Collation, words: &[&str]) -> Vec<String> {
+        let mut words =
+            words.iter().map(|w| Text::from(*w)).collect::<Vec<_>>();
+        words.sort_by_cached_key(|w| collation.key(w));
+        words.iter().map(Text::to_string).collect()
+    }
+
+    #[test]
+    fn test_collation() {
+        assert_eq!(
+            sort(Collation::Unicode, &["까", "각", "가게", "ㄱ"]),
+            ["ㄱ", "가게", "각", "까"]
+        );
+        assert_eq!(
+            sort(Collation::Jamo, &["과", "곡", "가게", "각"]),
+            ["각", "가게", "곡", "과"]
+        );
+        assert_eq!(
+            sort(Collation::Jamo, &["갓", "값", "갑"]),
+            ["갑", "값", "갓"]
+        );
+        assert_eq!(
+            sort(Collation::NorthKorean, &["까", "아", "하", "나", "짜"]),
+            ["나", "하", "까", "짜", "아"]
+        );
+        assert_eq!(
+            sort(Collation::NorthKorean, &["개", "기", "가"]),
+            ["가", "기", "개"]
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use crate::{
+    hangul::Hangul,
+    jamo::{FinalJamo, InitialJamo, MedialJamo},
+    syllable::Syllable,
+    text::{Segment, Text},
+};
+
+use super::{Connective, Level, Tense, Verb, hangul, initial};
+
+/// Verbs which follow -아/어 as helpers
+const HELPERS: [&str; 8] = [
+    "주다",
+    "드리다",
+    "보다",
+    "버리다",
+    "놓다",
+    "두다",
+    "있다",
+    "내다",
+];
+
+/// ## Lemma
+/// Dictionary form of a conjugated word and the ending it was seen with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lemma {
+    pub dictionary: Hangul,
+    pub ending: String,
+}
+
+/// ## Lemmatize
+/// Dictionary forms which conjugate to `seen`, e.g. `갔어요` is `가다`
+/// in the polite past. Candidate stems are found by undoing contractions
+/// and irregular changes on the syllables of `seen`, and are only kept if
+/// conjugating them gives `seen` back. Forms made with `-아/어` and a
+/// helping verb are read as well, e.g. `도와줘` is `돕다` with `주다`.
+///
+/// A lemma is dropped when one with a shorter stem gives `seen` with the
+/// same ending, as part of the ending was then read as its stem, e.g.
+/// `놀으다` for `놀아요` is `놀다`.
+pub fn lemmatize(seen: &Text) -> Vec<Lemma> {
+    let found = lemmas(seen, true);
+    found
+        .iter()
+        .filter(|l| {
+            !found.iter().any(|shorter| {
+                shorter.ending == l.ending
+                    && shorter.dictionary.len() < l.dictionary.len()
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+/// Lemmas of `seen`, reading a helping verb after it if `helpers`
+fn lemmas(seen: &Text, helpers: bool) -> Vec<Lemma> {
+    let first = match seen.first() {
+        Some(Segment::Hangul(h)) => h,
+        _ => return vec![],
+    };
+    let seen = seen.to_string();
+    let mut ret: Vec<Lemma> = vec![];
+    for n in 1..=first.len() {
+        let prefix: Hangul = first[..n].iter().copied().collect();
+        // A past ㅆ ends the stem, e.g. not 갔어다 for 갔어요
+        if first[..n - 1]
+            .iter()
+            .any(|s| s.finale() == Some(FinalJamo::Ss))
+        {
+            break;
+        }
+        for stem in stems(&prefix) {
+            let dictionary = super::join(stem, "다");
+            let verb = match Verb::new(&dictionary) {
+                Some(v) => v,
+                None => continue,
+            };
+            // A dictionary form is not read as 가드다 + 아
+            for ending in
+                endings(&verb, &seen, helpers).into_iter().filter(|e| {
+                    !seen.ends_with('다') || e.contains(Level::Formal.name())
+                })
+            {
+                let lemma = Lemma {
+                    dictionary: dictionary.clone(),
+                    ending,
+                };
+                if !ret.contains(&lemma) {
+                    ret.push(lemma);
+                }
+            }
+        }
+    }
+    // Most plausible first, then the one explaining most of `seen`
+    ret.sort_by_key(|l| (implausible(&l.dictionary), l.dictionary.len()));
+    ret
+}
+
+/// Signs of a stem which is really part of an ending, e.g. the past
+/// `ㅆ` in `먹었다`, `으` in `먹으다` or a doubled `ㄹ` in `몰르다`
+fn implausible(dictionary: &Hangul) -> usize {
+    let stem = &dictionary[..dictionary.len() - 1];
+    let last = match stem.last() {
+        Some(s) => s,
+        None => return 0,
+    };
+    let past = last.finale() == Some(FinalJamo::Ss)
+        && last.medial() != Some(MedialJamo::I);
+    let eu = stem.len() > 1
+        && last.initial() == Some(InitialJamo::Ng)
+        && last.medial() == Some(MedialJamo::Eu);
+    let doubled = stem.len() > 1
+        && last.initial() == Some(InitialJamo::R)
+        && stem[stem.len() - 2].finale() == Some(FinalJamo::R);
+    [past, eu, doubled].into_iter().filter(|b| *b).count()
+}
+
+/// Endings of `verb` which give `seen`, with a helping verb if `helpers`
+fn endings(verb: &Verb, seen: &str, helpers: bool) -> Vec<String> {
+    let mut ret = vec![];
+    for tense in Tense::all() {
+        for level in Level::all() {
+            if verb.form(tense, level).to_string() == seen {
+                ret.push(format!("{} {}", tense.name(), level.name()));
+            }
+        }
+    }
+    for connective in Connective::all() {
+        if verb.connective(connective).to_string() == seen {
+            ret.push(connective.name().into());
+        }
+    }
+    // Helping verbs follow -아/어, e.g. 도와 + 줘, but not each other,
+    // which would try every split of a long word
+    let infinitive = verb.infinitive().to_string();
+    if let Some(rest) = seen.strip_prefix(&infinitive)
+        && helpers
+        && !rest.is_empty()
+    {
+        for helper in lemmas(&Text::from(rest), false)
+            .into_iter()
+            .filter(|l| HELPERS.contains(&l.dictionary.to_string().as_str()))
+        {
+            ret.push(format!(
+                "-아/어 {} ({})",
+                helper.dictionary, helper.ending
+            ));
+        }
+    }
+    ret
+}
+
+/// Stems which `prefix` may have been conjugated from, undone
+/// contractions and irregular changes first
+fn stems(prefix: &Hangul) -> Vec<Hangul> {
+    let n = prefix.len();
+    let mut bases = vec![prefix.clone()];
+    // The final may belong to the ending, e.g. 갔, 갑 or 갈
+    if matches!(
+        prefix[n - 1].finale(),
+        Some(FinalJamo::Ss | FinalJamo::B | FinalJamo::R)
+    ) {
+        let mut base = prefix.clone();
+        base[n - 1].set_finale(None);
+        bases.push(base);
+    }
+    let mut ret = vec![];
+    for base in bases {
+        ret.extend(undo(&base));
+        ret.push(base);
+    }
+    // A stem ending in -아/어 or 다 is an ending read as a stem
+    ret.retain(|stem| match stem.as_slice() {
+        [.., s] if *s == hangul("다")[0] => false,
+        [_, .., s] => {
+            !(s.initial() == Some(InitialJamo::Ng)
+                && matches!(s.medial(), Some(MedialJamo::A | MedialJamo::Eo))
+                && s.finale().is_none())
+        }
+        _ => true,
+    });
+    ret
+}
+
+/// Undoes the changes a stem may have gone through to become `base`
+fn undo(base: &Hangul) -> Vec<Hangul> {
+    let n = base.len();
+    let last = base[n - 1];
+    let with = |medial: MedialJamo, finale: Option<FinalJamo>| {
+        let mut stem = base.clone();
+        stem[n - 1] = Syllable::new(initial(&last), medial, finale);
+        stem
+    };
+    let mut ret = vec![];
+    let medial = match (last.medial(), last.finale()) {
+        // 들 ← 듣
+        (Some(m), Some(FinalJamo::R)) => {
+            return vec![with(m, Some(FinalJamo::D))];
+        }
+        (Some(m), None) => m,
+        _ => return ret,
+    };
+    if n > 1 {
+        // 도와, 더워 and 더우 ← ㅂ
+        if last.initial() == Some(InitialJamo::Ng)
+            && matches!(medial, MedialJamo::Wa | MedialJamo::Wo | MedialJamo::U)
+        {
+            let mut stem: Hangul = base[..n - 1].iter().copied().collect();
+            stem[n - 2].set_finale(Some(FinalJamo::B));
+            ret.push(stem);
+        }
+        // 몰라 ← 모르
+        if last.initial() == Some(InitialJamo::R)
+            && matches!(medial, MedialJamo::A | MedialJamo::Eo)
+            && base[n - 2].finale() == Some(FinalJamo::R)
+        {
+            let mut stem = base.clone();
+            stem[n - 2].set_finale(None);
+            stem[n - 1] = Syllable::new(InitialJamo::R, MedialJamo::Eu, None);
+            ret.push(stem);
+        }
+    }
+    if last == hangul("해")[0] {
+        ret.push(with(MedialJamo::A, None));
+    }
+    // Contractions, e.g. 봐 ← 보 and 써 ← 쓰
+    match medial {
+        MedialJamo::Wa => ret.push(with(MedialJamo::O, None)),
+        MedialJamo::Wo => ret.push(with(MedialJamo::U, None)),
+        MedialJamo::Yeo => ret.push(with(MedialJamo::I, None)),
+        MedialJamo::Wae => ret.push(with(MedialJamo::Oe, None)),
+        MedialJamo::A | MedialJamo::Eo => ret.push(with(MedialJamo::Eu, None)),
+        _ => (),
+    }
+    // 그래 ← 그렇, the vowel in harmony with the syllable before
+    let bright = n > 1 && super::bright(base[n - 2].medial());
+    let h = Some(FinalJamo::H);
+    match (medial, bright) {
+        _ if last.initial() == Some(InitialJamo::H) => (),
+        (MedialJamo::Ae, true) => ret.push(with(MedialJamo::A, h)),
+        (MedialJamo::Ae, false) => ret.push(with(MedialJamo::Eo, h)),
+        (MedialJamo::Yae, true) => ret.push(with(MedialJamo::Ya, h)),
+        (MedialJamo::Yae, false) => ret.push(with(MedialJamo::Yeo, h)),
+        _ => (),
+    }
+    // ㅅ and ㄹ are dropped, e.g. 나아 ← 낫 and 삽니다 ← 살
+    ret.push(with(medial, Some(FinalJamo::S)));
+    ret.push(with(medial, Some(FinalJamo::R)));
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{conjugation::lemmatize, text::Text};
+
+    fn lemmas(seen: &str) -> Vec<String> {
+        lemmatize(&Text::from(seen))
+            .into_iter()
+            .map(|l| format!("{} {}", l.dictionary, l.ending))
+            .collect()
+    }
+
+    #[test]
+    fn test_lemmatize() {
+        let first = |seen| lemmas(seen).first().cloned().unwrap_or_default();
+        assert_eq!(first("갔어요"), "가다 Past 해요체");
+        assert_eq!(first("먹었습니다"), "먹다 Past 합니다체");
+        assert_eq!(first("먹을 거예요"), "먹다 Future 해요체");
+        assert_eq!(first("봐요"), "보다 Present 해요체");
+        assert_eq!(first("몰라요"), "모르다 Present 해요체");
+        assert_eq!(first("그래요"), "그렇다 Present 해요체");
+        assert_eq!(first("나아서"), "낫다 -아서/어서");
+        assert_eq!(first("공부했어"), "공부하다 Past 반말");
+        assert_eq!(first("도와줘"), "돕다 -아/어 주다 (Present 반말)");
+        assert!(lemmas("삽니다").contains(&"살다 Present 합니다체".into()));
+        assert!(lemmas("들어요").contains(&"듣다 Present 해요체".into()));
+        assert!(lemmas("사람").is_empty());
+        assert!(lemmas("가다").is_empty());
+        // Part of the ending is not read as the stem
+        for (seen, shorter, longer) in [
+            ("놀아요", "놀다", "놀으다"),
+            ("싶어요", "싶다", "싶으다"),
+            ("만들어요", "만들다", "만들으다"),
+        ] {
+            let found = lemmas(seen);
+            assert!(found.iter().any(|l| l.starts_with(shorter)), "{}", seen);
+            assert!(!found.iter().any(|l| l.starts_with(longer)), "{}", seen);
+        }
+        // Stems of the same length are kept, only a dictionary could tell
+        for (seen, lemma) in [
+            ("모아요", "모으다 Present 해요체"),
+            ("아파요", "아프다 Present 해요체"),
+            ("바빠요", "바쁘다 Present 해요체"),
+            ("만나요", "만나다 Present 해요체"),
+            ("자라요", "자라다 Present 해요체"),
+        ] {
+            assert!(lemmas(seen).contains(&lemma.into()), "{}", seen);
+        }
+    }
+
+    #[test]
+    fn test_helpers() {
+        let dictionary = |seen| {
+            lemmas(seen)
+                .into_iter()
+                .filter_map(|l| l.split(' ').next().map(String::from))
+                .collect::<Vec<_>>()
+        };
+        assert!(dictionary("먹어줘").contains(&"먹다".into()));
+        // Helping verbs are not stacked, which would try every split of
+        // a long word
+        assert!(!dictionary("먹어줘버려").contains(&"먹다".into()));
+    }
+}
@@ -0,0 +1,488 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use csv::ReaderBuilder;
+use serde::Deserialize;
+
+use crate::{
+    hangul::Hangul,
+    jamo::{FinalJamo, InitialJamo, MedialJamo},
+    syllable::Syllable,
+    text::Text,
+};
+
+mod lemma;
+
+pub use lemma::{Lemma, lemmatize};
+
+const VERBS: &str = include_str!("verbs.csv");
+
+/// One line of the verb table
+#[derive(Debug, Deserialize)]
+struct Row {
+    verb: String,
+    class: Option<Irregular>,
+}
+
+/// ## Irregular
+/// Class of a stem which changes before some endings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Irregular {
+    /// `ㅂ` becomes `우` before a vowel, e.g. `덥다` → `더워`
+    B,
+    /// `ㄷ` becomes `ㄹ` before a vowel, e.g. `듣다` → `들어`
+    D,
+    /// `ㅅ` is dropped before a vowel, e.g. `낫다` → `나아`
+    S,
+    /// `르` becomes `ㄹ라` or `ㄹ러`, e.g. `모르다` → `몰라`
+    Reu,
+    /// `ㅎ` is dropped and the vowel becomes `ㅐ`, e.g. `그렇다` → `그래`
+    H,
+    /// `ㅡ` is dropped before `아/어`, e.g. `쓰다` → `써`
+    Eu,
+    /// `ㄹ` is dropped before `ㄴ ㅂ ㅅ`, e.g. `살다` → `삽니다`
+    L,
+}
+impl Irregular {
+    pub fn name(self) -> &'static str {
+        match self {
+            Irregular::B => "ㅂ",
+            Irregular::D => "ㄷ",
+            Irregular::S => "ㅅ",
+            Irregular::Reu => "르",
+            Irregular::H => "ㅎ",
+            Irregular::Eu => "으",
+            Irregular::L => "ㄹ",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tense {
+    Present,
+    Past,
+    Future,
+}
+impl Tense {
+    pub fn all() -> [Tense; 3] {
+        [Tense::Present, Tense::Past, Tense::Future]
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Tense::Present => "Present",
+            Tense::Past => "Past",
+            Tense::Future => "Future",
+        }
+    }
+}
+
+/// ## Level
+/// Speech level of a sentence ending
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    /// 반말
+    Casual,
+    /// 해요체
+    Polite,
+    /// 합니다체
+    Formal,
+}
+impl Level {
+    pub fn all() -> [Level; 3] {
+        [Level::Casual, Level::Polite, Level::Formal]
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Casual => "반말",
+            Level::Polite => "해요체",
+            Level::Formal => "합니다체",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connective {
+    /// -고, and
+    Go,
+    /// -아서/어서, and so
+    Aseo,
+    /// -(으)면, if
+    Myeon,
+}
+impl Connective {
+    pub fn all() -> [Connective; 3] {
+        [Connective::Go, Connective::Aseo, Connective::Myeon]
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Connective::Go => "-고",
+            Connective::Aseo => "-아서/어서",
+            Connective::Myeon => "-(으)면",
+        }
+    }
+}
+
+/// ## Verb
+/// Verb or adjective conjugated from its dictionary form, e.g. `먹다`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verb {
+    stem: Hangul,
+    class: Option<Irregular>,
+}
+impl Verb {
+    /// `None` unless `dictionary` ends in `다`. The copula `이다` and
+    /// `아니다` follow rules of their own and are not conjugated.
+    pub fn new(dictionary: &Hangul) -> Option<Verb> {
+        let (da, stem) = dictionary.split_last()?;
+        if stem.is_empty()
+            || *da != hangul("다")[0]
+            || [hangul("이다"), hangul("아니다")].contains(dictionary)
+        {
+            return None;
+        }
+        let stem: Hangul = stem.iter().copied().collect();
+        let class = match table().get(dictionary) {
+            Some(class) => *class,
+            None => Self::guess_class(&stem),
+        };
+        Some(Verb { stem, class })
+    }
+
+    /// `None` for regular verbs
+    pub fn class(&self) -> Option<Irregular> {
+        self.class
+    }
+
+    pub fn form(&self, tense: Tense, level: Level) -> Text {
+        match tense {
+            Tense::Present => match level {
+                Level::Casual => self.infinitive().into(),
+                Level::Polite => join(self.infinitive(), "요").into(),
+                Level::Formal => self.formal(),
+            },
+            Tense::Past => {
+                let mut past = self.infinitive();
+                set_finale(&mut past, Some(FinalJamo::Ss));
+                match level {
+                    Level::Casual => join(past, "어").into(),
+                    Level::Polite => join(past, "어요").into(),
+                    Level::Formal => join(past, "습니다").into(),
+                }
+            }
+            Tense::Future => {
+                let (mut stem, eu) = self.before_eu();
+                match eu {
+                    true => stem = join(stem, "을"),
+                    false => set_finale(&mut stem, Some(FinalJamo::R)),
+                }
+                let mut ret = Text::from(stem);
+                ret.push_literal(" ");
+                let geo = match level {
+                    Level::Casual => "거야",
+                    Level::Polite => "거예요",
+                    Level::Formal => "겁니다",
+                };
+                for syllable in hangul(geo).iter() {
+                    ret.push_syllable(*syllable);
+                }
+                ret
+            }
+        }
+    }
+
+    pub fn connective(&self, connective: Connective) -> Hangul {
+        match connective {
+            Connective::Go => join(self.stem.clone(), "고"),
+            Connective::Aseo => join(self.infinitive(), "서"),
+            Connective::Myeon => match self.before_eu() {
+                (stem, true) => join(stem, "으면"),
+                (stem, false) => join(stem, "면"),
+            },
+        }
+    }
+
+    /// Stems ending in `ㅂ`, `ㅎ` and `르` are mostly irregular, `ㄹ` and
+    /// `ㅡ` always are
+    fn guess_class(stem: &Hangul) -> Option<Irregular> {
+        let last = stem.last()?;
+        match (last.initial(), last.medial(), last.finale()) {
+            (_, _, Some(FinalJamo::B)) => Some(Irregular::B),
+            (_, _, Some(FinalJamo::H)) => Some(Irregular::H),
+            (_, _, Some(FinalJamo::R)) => Some(Irregular::L),
+            (Some(InitialJamo::R), Some(MedialJamo::Eu), None)
+                if stem.len() > 1 =>
+            {
+                Some(Irregular::Reu)
+            }
+            (_, Some(MedialJamo::Eu), None) => Some(Irregular::Eu),
+            _ => None,
+        }
+    }
+
+    /// Stem with `-아/어`, the base of the present and past forms, e.g.
+    /// `먹어`, `가`, `해` or `몰라`
+    fn infinitive(&self) -> Hangul {
+        let mut stem = self.stem.clone();
+        let n = stem.len();
+        let last = stem[n - 1];
+        match self.class {
+            Some(Irregular::H) => {
+                let medial = match last.medial() {
+                    Some(MedialJamo::Ya | MedialJamo::Yeo) => MedialJamo::Yae,
+                    _ => MedialJamo::Ae,
+                };
+                stem[n - 1] = Syllable::new(initial(&last), medial, None);
+                return stem;
+            }
+            Some(Irregular::Reu) => {
+                let medial = match bright(stem[n - 2].medial()) {
+                    true => MedialJamo::A,
+                    false => MedialJamo::Eo,
+                };
+                stem[n - 2].set_finale(Some(FinalJamo::R));
+                stem[n - 1] = Syllable::new(InitialJamo::R, medial, None);
+                return stem;
+            }
+            Some(Irregular::B) => {
+                // Only 돕다 and 곱다 keep a bright vowel, i.e. 도와
+                let vowel = match last.medial() {
+                    Some(MedialJamo::O) if n == 1 => MedialJamo::O,
+                    _ => MedialJamo::U,
+                };
+                stem[n - 1].set_finale(None);
+                stem.push(vowel.into());
+            }
+            Some(Irregular::D) => stem[n - 1].set_finale(Some(FinalJamo::R)),
+            Some(Irregular::S) => {
+                // The dropped ㅅ still keeps the vowels apart
+                stem[n - 1].set_finale(None);
+                let ending = a_or_eo(&stem);
+                stem.push(ending.into());
+                return stem;
+            }
+            _ => (),
+        }
+        attach_a(stem)
+    }
+
+    /// Stem before endings starting with `으`, and whether the `으` is
+    /// kept, e.g. `먹으면` but `가면`, `살면` and `더우면`
+    fn before_eu(&self) -> (Hangul, bool) {
+        let mut stem = self.stem.clone();
+        let n = stem.len();
+        match self.class {
+            Some(Irregular::H) => {
+                stem[n - 1].set_finale(None);
+                (stem, false)
+            }
+            Some(Irregular::B) => {
+                stem[n - 1].set_finale(None);
+                stem.push(MedialJamo::U.into());
+                (stem, false)
+            }
+            Some(Irregular::D) => {
+                stem[n - 1].set_finale(Some(FinalJamo::R));
+                (stem, true)
+            }
+            Some(Irregular::S) => {
+                stem[n - 1].set_finale(None);
+                (stem, true)
+            }
+            Some(Irregular::L) => (stem, false),
+            _ => {
+                let eu = stem[n - 1].finale().is_some();
+                (stem, eu)
+            }
+        }
+    }
+
+    /// `-습니다` after a consonant, `-ㅂ니다` after a vowel or `ㄹ`
+    fn formal(&self) -> Text {
+        let mut stem = self.stem.clone();
+        let last = stem[stem.len() - 1];
+        match (self.class, last.finale()) {
+            (Some(Irregular::L), _) | (_, None) => {
+                set_finale(&mut stem, Some(FinalJamo::B));
+                join(stem, "니다").into()
+            }
+            _ => join(stem, "습니다").into(),
+        }
+    }
+}
+
+/// Adds `아` or `어` to `stem`, contracting it with a final vowel,
+/// e.g. `보` → `봐`, `마시` → `마셔` and `하` → `해`
+fn attach_a(mut stem: Hangul) -> Hangul {
+    let n = stem.len();
+    let last = stem[n - 1];
+    let ending = a_or_eo(&stem);
+    if last.finale().is_some() {
+        stem.push(ending.into());
+        return stem;
+    }
+    if last == hangul("하")[0] {
+        stem[n - 1] = hangul("해")[0];
+        return stem;
+    }
+    let contracted = match (last.medial(), ending) {
+        (Some(m @ (MedialJamo::A | MedialJamo::Eo)), _)
+        | (Some(m @ (MedialJamo::Ae | MedialJamo::E | MedialJamo::Yeo)), _) => {
+            Some(m)
+        }
+        (Some(MedialJamo::O), MedialJamo::A) => Some(MedialJamo::Wa),
+        (Some(MedialJamo::U), MedialJamo::Eo) => Some(MedialJamo::Wo),
+        (Some(MedialJamo::I), MedialJamo::Eo) => Some(MedialJamo::Yeo),
+        (Some(MedialJamo::Oe), MedialJamo::Eo) => Some(MedialJamo::Wae),
+        (Some(MedialJamo::Eu), ending) => Some(ending),
+        _ => None,
+    };
+    match contracted {
+        Some(medial) => {
+            stem[n - 1] = Syllable::new(initial(&last), medial, None)
+        }
+        None => stem.push(ending.into()),
+    }
+    stem
+}
+
+/// Vowel harmony, `아` after `ㅏ ㅗ ㅑ` and `어` otherwise. A final `ㅡ`
+/// takes the vowel of the syllable before it.
+fn a_or_eo(stem: &Hangul) -> MedialJamo {
+    let medial = match stem.as_slice() {
+        [.., before, last]
+            if last.medial() == Some(MedialJamo::Eu)
+                && last.finale().is_none() =>
+        {
+            before.medial()
+        }
+        [.., last] => last.medial(),
+        [] => None,
+    };
+    match bright(medial) {
+        true => MedialJamo::A,
+        false => MedialJamo::Eo,
+    }
+}
+
+fn bright(medial: Option<MedialJamo>) -> bool {
+    matches!(medial, Some(MedialJamo::A | MedialJamo::O | MedialJamo::Ya))
+}
+
+fn initial(syllable: &Syllable) -> InitialJamo {
+    syllable.initial().unwrap_or(InitialJamo::Ng)
+}
+
+fn set_finale(hangul: &mut Hangul, finale: Option<FinalJamo>) {
+    if let Some(last) = hangul.last_mut() {
+        last.set_finale(finale);
+    }
+}
+
+fn join(mut stem: Hangul, ending: &str) -> Hangul {
+    stem.extend(hangul(ending).iter());
+    stem
+}
+
+fn hangul(s: &str) -> Hangul {
+    Hangul::try_from(s).expect("Logic error!")
+}
+
+/// Verbs whose class is not told by their spelling
+fn table() -> &'static HashMap<Hangul, Option<Irregular>> {
+    static TABLE: OnceLock<HashMap<Hangul, Option<Irregular>>> =
+        OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut rdr = ReaderBuilder::new()
+            .delimiter(b';')
+            .comment(Some(b'#'))
+            .from_reader(VERBS.as_bytes());
+        rdr.deserialize::<Row>()
+            .map(|row| {
+                let row = row.expect("Invalid verb table");
+                (hangul(&row.verb), row.class)
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::conjugation::{
+        Connective, Irregular, Level, Tense, Verb, hangul,
+    };
+
+    fn forms(dictionary: &str) -> Vec<String> {
+        let verb = Verb::new(&hangul(dictionary)).unwrap();
+        let mut ret = vec![];
+        for tense in Tense::all() {
+            for level in Level::all() {
+                ret.push(verb.form(tense, level).to_string());
+            }
+        }
+        for connective in Connective::all() {
+            ret.push(verb.connective(connective).to_string());
+        }
+        ret
+    }
+
+    #[test]
+    fn test_regular() {
+        assert_eq!(
+            forms("먹다"),
+            [
+                "먹어",
+                "먹어요",
+                "먹습니다",
+                "먹었어",
+                "먹었어요",
+                "먹었습니다",
+                "먹을 거야",
+                "먹을 거예요",
+                "먹을 겁니다",
+                "먹고",
+                "먹어서",
+                "먹으면",
+            ]
+        );
+        assert_eq!(forms("가다")[..3], ["가", "가요", "갑니다"]);
+        assert_eq!(forms("보다")[3], "봤어");
+        assert_eq!(forms("마시다")[1], "마셔요");
+        assert_eq!(forms("되다")[1], "돼요");
+        assert_eq!(forms("쉬다")[1], "쉬어요");
+        assert_eq!(forms("공부하다")[4], "공부했어요");
+        assert_eq!(forms("좋다")[1], "좋아요");
+        assert_eq!(Verb::new(&hangul("먹")), None);
+        assert_eq!(Verb::new(&hangul("이다")), None);
+    }
+
+    #[test]
+    fn test_irregular() {
+        let class = |s| Verb::new(&hangul(s)).unwrap().class();
+        assert_eq!(class("덥다"), Some(Irregular::B));
+        assert_eq!(class("입다"), None);
+        assert_eq!(class("듣다"), Some(Irregular::D));
+        assert_eq!(class("받다"), None);
+        assert_eq!(forms("덥다")[1], "더워요");
+        assert_eq!(forms("덥다")[11], "더우면");
+        assert_eq!(forms("돕다")[1], "도와요");
+        assert_eq!(forms("자유롭다")[1], "자유로워요");
+        assert_eq!(forms("듣다")[1], "들어요");
+        assert_eq!(forms("듣다")[6], "들을 거야");
+        assert_eq!(forms("낫다")[1], "나아요");
+        assert_eq!(forms("낫다")[11], "나으면");
+        assert_eq!(forms("모르다")[1], "몰라요");
+        assert_eq!(forms("부르다")[4], "불렀어요");
+        assert_eq!(forms("따르다")[1], "따라요");
+        assert_eq!(forms("그렇다")[1], "그래요");
+        assert_eq!(forms("그렇다")[11], "그러면");
+        assert_eq!(forms("하얗다")[0], "하얘");
+        assert_eq!(forms("쓰다")[1], "써요");
+        assert_eq!(forms("바쁘다")[1], "바빠요");
+        assert_eq!(forms("살다")[2], "삽니다");
+        assert_eq!(forms("살다")[7], "살 거예요");
+        assert_eq!(forms("만들다")[11], "만들면");
+    }
+}
@@ -0,0 +1,172 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{backup, schema};
+
+/// Deck opened when none is named
+pub const DEFAULT_DECK: &str = "default";
+const EXTENSION: &str = "csv";
+/// Where the log was kept before decks, in the working directory
+const LEGACY_LOG: &str = "hangul-log.csv";
+
+/// ## Decks
+/// Separate logs, each saved as `<name>.csv` in a data directory
+#[derive(Debug, Clone)]
+pub struct Decks {
+    dir: PathBuf,
+}
+impl Decks {
+    /// Decks in `dir`, or in `$XDG_DATA_HOME/langlog` if not given,
+    /// creating the directory if missing. A log left in the working
+    /// directory by an older version becomes the default deck.
+    pub fn open(dir: Option<PathBuf>) -> DeckResult<Self> {
+        let dir = match dir {
+            Some(dir) => dir,
+            None => default_dir()?,
+        };
+        fs::create_dir_all(&dir)?;
+        let ret = Self { dir };
+        let default = ret.path(DEFAULT_DECK)?;
+        let legacy = env::current_dir()?.join(LEGACY_LOG);
+        if !default.exists() && legacy.exists() {
+            log::info!("Importing {:?} as deck {}", legacy, DEFAULT_DECK);
+            fs::copy(legacy, default)?;
+        }
+        Ok(ret)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Names of every deck, sorted
+    pub fn names(&self) -> DeckResult<Vec<String>> {
+        let mut ret = vec![];
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|e| e == EXTENSION)
+                && let Some(name) = path.file_stem().and_then(|s| s.to_str())
+            {
+                ret.push(name.to_string());
+            }
+        }
+        ret.sort();
+        Ok(ret)
+    }
+
+    /// File of the deck `name`, which may not exist yet
+    pub fn path(&self, name: &str) -> DeckResult<PathBuf> {
+        let valid = !name.is_empty()
+            && !name.starts_with('.')
+            && !name
+                .chars()
+                .any(|c| matches!(c, '/' | '\\') || c.is_control());
+        match valid {
+            true => Ok(self.dir.join(format!("{}.{}", name, EXTENSION))),
+            false => Err(DeckError::InvalidName(name.into())),
+        }
+    }
+
+    pub fn exists(&self, name: &str) -> bool {
+        self.path(name).is_ok_and(|p| p.exists())
+    }
+
+    /// Creates the empty deck `name`
+    pub fn create(&self, name: &str) -> DeckResult<PathBuf> {
+        let path = self.path(name)?;
+        if path.exists() {
+            return Err(DeckError::Exists(name.into()));
+        }
+        schema::write(&path, [])?;
+        Ok(path)
+    }
+
+    pub fn rename(&self, from: &str, to: &str) -> DeckResult<PathBuf> {
+        let (from_path, to_path) = (self.path(from)?, self.path(to)?);
+        if !from_path.exists() {
+            return Err(DeckError::NotFound(from.into()));
+        }
+        if to_path.exists() {
+            return Err(DeckError::Exists(to.into()));
+        }
+        fs::rename(&from_path, &to_path)?;
+        backup::rename(&from_path, &to_path)?;
+        Ok(to_path)
+    }
+
+    /// Deletes the deck `name` with its backups
+    pub fn delete(&self, name: &str) -> DeckResult<()> {
+        let path = self.path(name)?;
+        if !path.exists() {
+            return Err(DeckError::NotFound(name.into()));
+        }
+        fs::remove_file(&path)?;
+        Ok(backup::delete(&path)?)
+    }
+}
+
+/// `$XDG_DATA_HOME/langlog`, falling back to `~/.local/share/langlog`
+fn default_dir() -> DeckResult<PathBuf> {
+    let absolute = |var| {
+        env::var_os(var)
+            .map(PathBuf::from)
+            .filter(|p| p.is_absolute())
+    };
+    match (absolute("XDG_DATA_HOME"), absolute("HOME")) {
+        (Some(data), _) => Ok(data.join("langlog")),
+        (None, Some(home)) => Ok(home.join(".local/share/langlog")),
+        (None, None) => Err(DeckError::NoDataDir),
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeckError {
+    #[error("No data directory, set $XDG_DATA_HOME or use --data-dir")]
+    NoDataDir,
+    #[error("Invalid deck name: {0:?}")]
+    InvalidName(String),
+    #[error("Deck {0} already exists")]
+    Exists(String),
+    #[error("Deck {0} does not exist")]
+    NotFound(String),
+    #[error("Deck {0} is open, switch to another first")]
+    Open(String),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+pub type DeckResult<T> = Result<T, DeckError>;
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use crate::{backup, deck::Decks};
+
+    #[test]
+    fn test_decks() {
+        let dir = env::temp_dir().join(format!("langlog-{}", process::id()));
+        let decks = Decks::open(Some(dir.clone())).unwrap();
+        assert!(decks.path("").is_err());
+        assert!(decks.path("../escape").is_err());
+        assert!(decks.path(".hidden").is_err());
+        assert!(decks.path("한국어 1").is_ok());
+
+        decks.create("b").unwrap();
+        decks.create("a").unwrap();
+        assert!(decks.create("a").is_err());
+        assert_eq!(decks.names().unwrap(), ["a", "b"]);
+        decks.rename("a", "c").unwrap();
+        assert!(decks.rename("b", "c").is_err());
+        let b = decks.path("b").unwrap();
+        backup::rotate(&b).unwrap();
+        assert!(!backup::list(&b).is_empty());
+        decks.delete("b").unwrap();
+        assert!(backup::list(&b).is_empty());
+        assert!(decks.delete("b").is_err());
+        assert!(!decks.names().unwrap().contains(&"a".to_string()));
+        assert!(decks.exists("c"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
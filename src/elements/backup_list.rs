@@ -0,0 +1,130 @@
+use std::ops::Range;
+
+use terminal::{
+    code::TerminalCode,
+    elements::Dispatch,
+    event::{KeyCode, KeyEvent, KeyEventKind},
+    style::ContentStyle,
+    traits::{Block, Input},
+};
+
+use crate::{
+    backup::{self, Backup},
+    elements::{ChoiceList, Log},
+    ext::date_time,
+    schema,
+};
+
+macro_rules! enter {
+    () => {
+        KeyEvent {
+            code: KeyCode::Enter,
+            kind: KeyEventKind::Press,
+            ..
+        }
+    };
+}
+
+/// ## Backup List
+/// Backups of the file of `log`, newest first, with how many entries
+/// each has. Enter restores the chosen one.
+pub struct BackupList {
+    pos: (u16, u16, u16),
+    height: u16,
+    log: Dispatch<Log>,
+    /// Backups with their count of entries, the line after them is the
+    /// message
+    backups: ChoiceList<(Backup, usize)>,
+    message: String,
+}
+impl BackupList {
+    pub fn new(pos: (u16, u16, u16), height: u16, log: Dispatch<Log>) -> Self {
+        Self {
+            pos,
+            height,
+            log,
+            backups: ChoiceList::new(height.saturating_sub(2) as usize),
+            message: String::new(),
+        }
+    }
+
+    /// Lists the backups again, e.g. after the log moved to another deck
+    pub fn refresh(&mut self) {
+        let path = self.log.read().unwrap().path().to_path_buf();
+        self.backups.set_items(
+            backup::list(&path)
+                .into_iter()
+                .map(|b| {
+                    let entries = schema::read(&b.path).map_or(0, |r| r.len());
+                    (b, entries)
+                })
+                .collect(),
+        );
+        self.message = match self.backups.is_empty() {
+            true => "No backups yet".into(),
+            false => String::new(),
+        };
+    }
+}
+impl Block for BackupList {
+    fn pos(&self) -> (u16, u16, u16) {
+        self.pos
+    }
+
+    fn rel_line(&self, i: u16) -> Option<String> {
+        let i = i as usize;
+        if i >= self.height as usize {
+            return None;
+        }
+        if i + 1 == self.height as usize {
+            return Some(self.message.clone());
+        }
+        Some(
+            self.backups
+                .shown(i)
+                .map(|(b, entries)| {
+                    format!("{} UTC  {} entries", date_time(b.taken), entries)
+                })
+                .unwrap_or_default(),
+        )
+    }
+
+    fn style_line(&self, i: u16) -> Vec<(Range<usize>, ContentStyle)> {
+        self.backups.style_line(i as usize)
+    }
+}
+impl Input for BackupList {
+    fn feed(&mut self, key: KeyEvent) -> TerminalCode {
+        match key {
+            enter!() => {
+                if let Some((b, _)) = self.backups.chosen() {
+                    let res = self.log.write().unwrap().restore(&b.path);
+                    match res {
+                        Ok(()) => {
+                            return TerminalCode::PreviousSceneWithFocus(4);
+                        }
+                        Err(e) => self.message = e.to_string(),
+                    }
+                }
+                TerminalCode::None
+            }
+            _ => self.backups.feed(key),
+        }
+    }
+
+    fn rel_cursor_pos(&self) -> Option<(u16, u16)> {
+        None
+    }
+
+    fn input_pos(&self) -> (u16, u16) {
+        (self.pos.0, self.pos.1)
+    }
+
+    fn focus(&mut self) {
+        self.backups.focus()
+    }
+
+    fn unfocus(&mut self) {
+        self.backups.unfocus()
+    }
+}
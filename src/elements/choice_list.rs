@@ -0,0 +1,108 @@
+use std::ops::Range;
+
+use terminal::{
+    code::TerminalCode,
+    event::{KeyCode, KeyEvent, KeyEventKind},
+    style::{Color, ContentStyle},
+};
+
+macro_rules! key {
+    ($code:pat) => {
+        KeyEvent {
+            code: $code,
+            kind: KeyEventKind::Press,
+            ..
+        }
+    };
+}
+
+/// ## Choice List
+/// Items shown one per line, scrolled to keep the chosen one in view and
+/// highlighted while focused. Up and Down move the choice, the element
+/// showing the list handles the rest.
+#[derive(Debug, Clone)]
+pub struct ChoiceList<T> {
+    items: Vec<T>,
+    /// Lines the items are shown on
+    rows: usize,
+    index: usize,
+    focused: bool,
+}
+impl<T> ChoiceList<T> {
+    pub fn new(rows: usize) -> Self {
+        Self {
+            items: vec![],
+            rows,
+            index: 0,
+            focused: false,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Replaces the items, choosing the first
+    pub fn set_items(&mut self, items: Vec<T>) {
+        self.items = items;
+        self.index = 0;
+    }
+
+    /// Replaces the items, keeping the choice at its place if there
+    /// still is one
+    pub fn update_items(&mut self, items: Vec<T>) {
+        self.items = items;
+        self.index = self.index.min(self.items.len().saturating_sub(1));
+    }
+
+    pub fn chosen(&self) -> Option<&T> {
+        self.items.get(self.index)
+    }
+
+    /// Item shown on line `i` of the list
+    pub fn shown(&self, i: usize) -> Option<&T> {
+        match i < self.rows {
+            true => self.items.get(self.offset() + i),
+            false => None,
+        }
+    }
+
+    pub fn style_line(&self, i: usize) -> Vec<(Range<usize>, ContentStyle)> {
+        if !self.focused || i >= self.rows || self.offset() + i != self.index {
+            return vec![];
+        }
+        vec![(
+            0..usize::MAX,
+            ContentStyle {
+                foreground_color: Some(Color::Black),
+                background_color: Some(Color::White),
+                ..Default::default()
+            },
+        )]
+    }
+
+    pub fn feed(&mut self, key: KeyEvent) -> TerminalCode {
+        match key {
+            key!(KeyCode::Up) => self.index = self.index.saturating_sub(1),
+            key!(KeyCode::Down) => {
+                self.index =
+                    (self.index + 1).min(self.items.len().saturating_sub(1))
+            }
+            _ => return TerminalCode::UnhandledKey(key),
+        }
+        TerminalCode::None
+    }
+
+    pub fn focus(&mut self) {
+        self.focused = true
+    }
+
+    pub fn unfocus(&mut self) {
+        self.focused = false
+    }
+
+    /// First shown item, keeping the chosen one in view
+    fn offset(&self) -> usize {
+        (self.index + 1).saturating_sub(self.rows)
+    }
+}
@@ -0,0 +1,78 @@
+use terminal::{elements::Dispatch, traits::Block};
+
+use crate::{
+    conjugation::{Connective, Level, Tense, Verb},
+    elements::Log,
+    text::{Segment, Text},
+};
+
+/// ## Conjugation Info
+/// Conjugates the selected entry of `log` if it is a verb or adjective
+/// in its dictionary form
+pub struct ConjugationInfo {
+    pos: (u16, u16, u16),
+    log: Dispatch<Log>,
+}
+impl ConjugationInfo {
+    pub fn new(pos: (u16, u16, u16), log: Dispatch<Log>) -> Self {
+        Self { pos, log }
+    }
+
+    fn lines(&self) -> Vec<String> {
+        let log = self.log.read().unwrap();
+        let text = match log.current_hangul() {
+            Some(t) => t,
+            None => return vec!["No entry selected".into()],
+        };
+        let verb = match text.as_slice() {
+            [Segment::Hangul(h)] => Verb::new(h),
+            _ => None,
+        };
+        let verb = match verb {
+            Some(v) => v,
+            None => {
+                return vec![
+                    format!("{}", text),
+                    "".into(),
+                    "Not a verb or adjective ending in 다".into(),
+                ];
+            }
+        };
+        let class = match verb.class() {
+            Some(c) => format!("{} irregular", c.name()),
+            None => "regular".into(),
+        };
+        let levels = Level::all().map(Level::name).join(" / ");
+        let mut ret = vec![
+            format!("{} ({})", text, class),
+            "".into(),
+            format!("{:<10}{}", "", levels),
+        ];
+        for tense in Tense::all() {
+            let forms = Level::all()
+                .map(|l| verb.form(tense, l))
+                .iter()
+                .map(Text::to_string)
+                .collect::<Vec<_>>();
+            ret.push(format!("{:<10}{}", tense.name(), forms.join(" / ")));
+        }
+        ret.push("".into());
+        for connective in Connective::all() {
+            ret.push(format!(
+                "{:<12}{}",
+                connective.name(),
+                verb.connective(connective)
+            ));
+        }
+        ret
+    }
+}
+impl Block for ConjugationInfo {
+    fn pos(&self) -> (u16, u16, u16) {
+        self.pos
+    }
+
+    fn rel_line(&self, i: u16) -> Option<String> {
+        self.lines().into_iter().nth(i as usize)
+    }
+}
@@ -0,0 +1,213 @@
+use std::ops::Range;
+
+use terminal::{
+    code::TerminalCode,
+    elements::Dispatch,
+    event::{KeyCode, KeyEvent, KeyEventKind},
+    style::ContentStyle,
+    traits::{Block, Input},
+};
+
+use crate::{
+    deck::{DeckError, DeckResult, Decks},
+    elements::{ChoiceList, Log},
+};
+
+macro_rules! enter {
+    () => {
+        KeyEvent {
+            code: KeyCode::Enter,
+            kind: KeyEventKind::Press,
+            ..
+        }
+    };
+}
+
+/// ## Deck List
+/// Decks to choose from, the one `log` is saved to marked with `*`.
+/// Enter opens the chosen deck, the last line tells how the last change
+/// went.
+pub struct DeckList {
+    pos: (u16, u16, u16),
+    height: u16,
+    decks: Decks,
+    log: Dispatch<Log>,
+    /// Deck `log` is saved to
+    current: String,
+    /// Names of the decks, the line after them is the message
+    names: ChoiceList<String>,
+    message: String,
+    /// Deck to delete on the next [`DeckList::delete_chosen`]
+    to_delete: Option<String>,
+}
+impl DeckList {
+    pub fn new(
+        pos: (u16, u16, u16),
+        height: u16,
+        decks: Decks,
+        current: String,
+        log: Dispatch<Log>,
+    ) -> Self {
+        let mut ret = Self {
+            pos,
+            height,
+            decks,
+            log,
+            current,
+            names: ChoiceList::new(height.saturating_sub(2) as usize),
+            message: String::new(),
+            to_delete: None,
+        };
+        ret.refresh();
+        ret
+    }
+
+    /// Creates the deck `name` and opens it
+    pub fn create(&mut self, name: &str) -> bool {
+        let res = self
+            .decks
+            .create(name)
+            .and_then(|_| self.open(name))
+            .map(|_| format!("Created {}", name));
+        self.report(res)
+    }
+
+    /// Renames the chosen deck to `name`
+    pub fn rename_chosen(&mut self, name: &str) -> bool {
+        let res = match self.chosen() {
+            Some(from) => self.rename(&from, name),
+            None => return self.nothing_chosen(),
+        };
+        self.report(res)
+    }
+
+    /// Deletes the chosen deck once asked twice in a row
+    pub fn delete_chosen(&mut self) -> bool {
+        let res = match self.chosen() {
+            None => return self.nothing_chosen(),
+            Some(name) if name == self.current => Err(DeckError::Open(name)),
+            Some(name) if self.to_delete.as_ref() != Some(&name) => {
+                let message =
+                    format!("Delete again to delete {} and its backups", name);
+                self.to_delete = Some(name);
+                self.message = message;
+                return false;
+            }
+            Some(name) => self
+                .decks
+                .delete(&name)
+                .map(|_| format!("Deleted {}", name)),
+        };
+        self.report(res)
+    }
+
+    fn open(&mut self, name: &str) -> DeckResult<()> {
+        if !self.decks.exists(name) {
+            return Err(DeckError::NotFound(name.into()));
+        }
+        let path = self.decks.path(name)?;
+        self.log.write().unwrap().open(path)?;
+        self.current = name.into();
+        Ok(())
+    }
+
+    fn rename(&mut self, from: &str, to: &str) -> DeckResult<String> {
+        // The open deck is saved first, so nothing is left behind
+        if from == self.current {
+            self.log.read().unwrap().save_if_read()?;
+        }
+        let path = self.decks.rename(from, to)?;
+        if from == self.current {
+            self.log.write().unwrap().set_path(path);
+            self.current = to.into();
+        }
+        Ok(format!("Renamed {} to {}", from, to))
+    }
+
+    fn chosen(&self) -> Option<String> {
+        self.names.chosen().cloned()
+    }
+
+    fn nothing_chosen(&mut self) -> bool {
+        self.message = "Choose a deck in the list first".into();
+        false
+    }
+
+    /// Shows how `res` went, `true` if it succeeded
+    fn report(&mut self, res: DeckResult<String>) -> bool {
+        self.to_delete = None;
+        let ok = res.is_ok();
+        self.message = res.unwrap_or_else(|e| e.to_string());
+        self.refresh();
+        ok
+    }
+
+    fn refresh(&mut self) {
+        match self.decks.names() {
+            Ok(names) => self.names.update_items(names),
+            Err(e) => self.message = e.to_string(),
+        }
+    }
+}
+impl Block for DeckList {
+    fn pos(&self) -> (u16, u16, u16) {
+        self.pos
+    }
+
+    fn rel_line(&self, i: u16) -> Option<String> {
+        let i = i as usize;
+        if i >= self.height as usize {
+            return None;
+        }
+        if i + 1 == self.height as usize {
+            return Some(self.message.clone());
+        }
+        Some(
+            self.names
+                .shown(i)
+                .map(|name| match *name == self.current {
+                    true => format!("* {}", name),
+                    false => format!("  {}", name),
+                })
+                .unwrap_or_default(),
+        )
+    }
+
+    fn style_line(&self, i: u16) -> Vec<(Range<usize>, ContentStyle)> {
+        self.names.style_line(i as usize)
+    }
+}
+impl Input for DeckList {
+    fn feed(&mut self, key: KeyEvent) -> TerminalCode {
+        match key {
+            enter!() => {
+                if let Some(name) = self.chosen() {
+                    let res =
+                        self.open(&name).map(|_| format!("Opened {}", name));
+                    if self.report(res) {
+                        return TerminalCode::PreviousScene;
+                    }
+                }
+                TerminalCode::None
+            }
+            _ => self.names.feed(key),
+        }
+    }
+
+    fn rel_cursor_pos(&self) -> Option<(u16, u16)> {
+        None
+    }
+
+    fn input_pos(&self) -> (u16, u16) {
+        (self.pos.0, self.pos.1)
+    }
+
+    fn focus(&mut self) {
+        self.names.focus();
+        self.refresh();
+    }
+
+    fn unfocus(&mut self) {
+        self.names.unfocus()
+    }
+}
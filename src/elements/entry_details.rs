@@ -0,0 +1,253 @@
+use std::ops::Range;
+
+use terminal::{
+    code::TerminalCode,
+    elements::{Dispatch, TextLine},
+    event::{KeyCode, KeyEvent, KeyEventKind},
+    style::{Color, ContentStyle},
+    traits::{Block, Input},
+};
+
+use crate::{
+    elements::{Details, Log, Sense},
+    ext::date,
+    schema::PartOfSpeech,
+};
+
+macro_rules! key {
+    ($code:pat) => {
+        KeyEvent {
+            code: $code,
+            kind: KeyEventKind::Press,
+            ..
+        }
+    };
+}
+
+/// Width of the field labels
+const LABEL: usize = 16;
+const LABELS: [&str; 4] = ["Part of speech", "Tags", "Example", "Notes"];
+
+/// Fields as loaded from, and to be saved to, an entry
+#[derive(Debug, Clone, Default)]
+struct Form {
+    key: Option<Sense>,
+    /// Tells the senses of a word apart
+    description: String,
+    details: Details,
+    /// Tags, example and notes
+    inputs: [TextLine; 3],
+}
+impl Form {
+    fn load(log: &Log, width: u16) -> Self {
+        let details = log.current_details().cloned().unwrap_or_default();
+        let input = |value: String| {
+            TextLine::default()
+                .with_width(width)
+                .with_value(value)
+                .clone()
+        };
+        Self {
+            key: log.current_sense().cloned(),
+            description: log.current_description().unwrap_or_default().into(),
+            inputs: [
+                input(details.tags.join(", ")),
+                input(details.example.clone()),
+                input(details.notes.clone()),
+            ],
+            details,
+        }
+    }
+
+    fn details(&self) -> Details {
+        let [tags, example, notes] = &self.inputs;
+        Details {
+            tags: tags
+                .value()
+                .split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(String::from)
+                .collect(),
+            example: example.value().into(),
+            notes: notes.value().into(),
+            ..self.details.clone()
+        }
+    }
+}
+
+/// ## Entry Details
+/// Edits the part of speech, tags, example and notes of the selected
+/// entry of `log`
+pub struct EntryDetails {
+    pos: (u16, u16, u16),
+    width: u16,
+    log: Dispatch<Log>,
+    form: Form,
+    row: usize,
+    focused: bool,
+}
+impl EntryDetails {
+    pub fn new(pos: (u16, u16, u16), width: u16, log: Dispatch<Log>) -> Self {
+        Self {
+            pos,
+            width,
+            log,
+            form: Form::default(),
+            row: 0,
+            focused: false,
+        }
+    }
+
+    /// Whether another entry was selected since the fields were loaded
+    fn is_stale(&self) -> bool {
+        self.log.read().unwrap().current_sense() != self.form.key.as_ref()
+    }
+
+    fn sync(&mut self) {
+        if self.is_stale() {
+            self.form =
+                Form::load(&self.log.read().unwrap(), self.input_width());
+            self.row = 0;
+        }
+    }
+
+    fn input_width(&self) -> u16 {
+        self.width.saturating_sub(LABEL as u16)
+    }
+
+    fn lines(&self) -> Vec<String> {
+        let fresh;
+        let form = match self.is_stale() {
+            true => {
+                fresh =
+                    Form::load(&self.log.read().unwrap(), self.input_width());
+                &fresh
+            }
+            false => &self.form,
+        };
+        let hangul = match &form.key {
+            Some((hangul, _)) => hangul,
+            None => return vec!["No entry selected".into()],
+        };
+        let part_of_speech =
+            form.details.part_of_speech.map_or("-", PartOfSpeech::name);
+        let when = |secs| match secs {
+            0 => "unknown".into(),
+            secs => date(secs),
+        };
+        let mut ret =
+            vec![format!("{}  {}", hangul, form.description), "".into()];
+        ret.push(format!("{:<LABEL$}< {} >", LABELS[0], part_of_speech));
+        for (label, input) in LABELS[1..].iter().zip(form.inputs.iter()) {
+            ret.push("".into());
+            ret.push(format!(
+                "{:<LABEL$}{}",
+                label,
+                input.rel_line(0).unwrap_or_default()
+            ));
+        }
+        ret.extend([
+            "".into(),
+            format!(
+                "Added {}, changed {}",
+                when(form.details.created),
+                when(form.details.modified)
+            ),
+            "".into(),
+            "Up/Down: field  Left/Right: part of speech".into(),
+            "Enter: save  Esc: close".into(),
+        ]);
+        ret
+    }
+
+    /// Next part of speech after the current one, `None` between the
+    /// last and the first
+    fn cycle_part_of_speech(&mut self, forward: bool) {
+        let mut all = vec![None];
+        all.extend(PartOfSpeech::all().map(Some));
+        let i = all
+            .iter()
+            .position(|p| *p == self.form.details.part_of_speech)
+            .unwrap_or(0);
+        let i = match forward {
+            true => (i + 1) % all.len(),
+            false => (i + all.len() - 1) % all.len(),
+        };
+        self.form.details.part_of_speech = all[i];
+    }
+}
+impl Block for EntryDetails {
+    fn pos(&self) -> (u16, u16, u16) {
+        self.pos
+    }
+
+    fn rel_line(&self, i: u16) -> Option<String> {
+        self.lines().into_iter().nth(i as usize)
+    }
+
+    fn style_line(&self, i: u16) -> Vec<(Range<usize>, ContentStyle)> {
+        if !self.focused || i as usize != 2 + 2 * self.row {
+            return vec![];
+        }
+        vec![(
+            0..LABELS[self.row].len(),
+            ContentStyle {
+                foreground_color: Some(Color::Black),
+                background_color: Some(Color::White),
+                ..Default::default()
+            },
+        )]
+    }
+}
+impl Input for EntryDetails {
+    fn feed(&mut self, key: KeyEvent) -> TerminalCode {
+        self.sync();
+        let key_of_entry = match &self.form.key {
+            Some(k) => k.clone(),
+            None => return TerminalCode::UnhandledKey(key),
+        };
+        match (key, self.row) {
+            (key!(KeyCode::Up), _) => self.row = self.row.saturating_sub(1),
+            (key!(KeyCode::Down), _) => {
+                self.row = (self.row + 1).min(LABELS.len() - 1)
+            }
+            (key!(KeyCode::Left), 0) => self.cycle_part_of_speech(false),
+            (key!(KeyCode::Right), 0) => self.cycle_part_of_speech(true),
+            (key!(KeyCode::Enter), _) => {
+                self.log
+                    .write()
+                    .unwrap()
+                    .set_details(&key_of_entry, self.form.details());
+                self.form.key = None;
+                return TerminalCode::PreviousSceneWithFocus(4);
+            }
+            (key, 0) => return TerminalCode::UnhandledKey(key),
+            (key, row) => return self.form.inputs[row - 1].feed(key),
+        }
+        TerminalCode::None
+    }
+
+    fn rel_cursor_pos(&self) -> Option<(u16, u16)> {
+        let y = 2 + 2 * self.row as u16;
+        match self.row {
+            _ if self.is_stale() => None,
+            0 => None,
+            row => self.form.inputs[row - 1]
+                .rel_cursor_pos()
+                .map(|(x, _)| (LABEL as u16 + x, y)),
+        }
+    }
+
+    fn input_pos(&self) -> (u16, u16) {
+        (self.pos.0, self.pos.1)
+    }
+
+    fn focus(&mut self) {
+        self.focused = true
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = false
+    }
+}
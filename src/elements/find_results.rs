@@ -0,0 +1,121 @@
+use std::ops::Range;
+
+use terminal::{
+    code::TerminalCode,
+    event::{KeyCode, KeyEvent, KeyEventKind},
+    style::ContentStyle,
+    traits::{Block, Input},
+};
+
+use crate::elements::{ChoiceList, Sense};
+
+macro_rules! enter {
+    () => {
+        KeyEvent {
+            code: KeyCode::Enter,
+            kind: KeyEventKind::Press,
+            ..
+        }
+    };
+}
+
+/// ## Find Results
+/// Ranked log entries found by a search. `on_choose` gets the one
+/// chosen with Enter.
+pub struct FindResults<F> {
+    pos: (u16, u16, u16),
+    height: u16,
+    /// Sense and description of every match
+    results: ChoiceList<(Sense, String)>,
+    searched: bool,
+    on_choose: F,
+}
+impl<F> FindResults<F>
+where
+    F: Fn(&Sense) -> TerminalCode,
+{
+    pub fn new(pos: (u16, u16, u16), height: u16, on_choose: F) -> Self {
+        Self {
+            pos,
+            height,
+            results: ChoiceList::new(height as usize),
+            searched: false,
+            on_choose,
+        }
+    }
+
+    pub fn set_results(&mut self, results: Vec<(Sense, String)>) {
+        self.results.set_items(results);
+        self.searched = true;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.results.set_items(vec![]);
+        self.searched = false;
+    }
+}
+impl<F> Block for FindResults<F> {
+    fn pos(&self) -> (u16, u16, u16) {
+        self.pos
+    }
+
+    fn rel_line(&self, i: u16) -> Option<String> {
+        if i >= self.height {
+            return None;
+        }
+        if self.results.is_empty() {
+            return match (i, self.searched) {
+                (0, true) => Some("No entries found".into()),
+                _ => Some("".into()),
+            };
+        }
+        Some(
+            self.results
+                .shown(i as usize)
+                .map(|((h, _), d)| format!("{}  {}", h, d))
+                .unwrap_or_default(),
+        )
+    }
+
+    fn style_line(&self, i: u16) -> Vec<(Range<usize>, ContentStyle)> {
+        self.results.style_line(i as usize)
+    }
+}
+impl<F> Input for FindResults<F>
+where
+    F: Fn(&Sense) -> TerminalCode,
+{
+    fn feed(&mut self, key: KeyEvent) -> TerminalCode {
+        match key {
+            enter!() => {
+                if let Some((key, _)) = self.results.chosen() {
+                    let code = (self.on_choose)(key);
+                    self.clear();
+                    return code;
+                }
+                TerminalCode::None
+            }
+            _ => self.results.feed(key),
+        }
+    }
+
+    fn rel_cursor_pos(&self) -> Option<(u16, u16)> {
+        None
+    }
+
+    fn input_pos(&self) -> (u16, u16) {
+        (self.pos.0, self.pos.1)
+    }
+
+    fn focus(&mut self) {
+        self.results.focus()
+    }
+
+    fn unfocus(&mut self) {
+        self.results.unfocus()
+    }
+}
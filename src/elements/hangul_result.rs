@@ -5,32 +5,152 @@ use terminal::{
     traits::Block,
 };
 
-use crate::{hangul::Hangul, hangul_parser::HangulParser, syllable::Syllable};
+use crate::{
+    hangul::Hangul,
+    hangul_parser::HangulParser,
+    hanja::{self, is_hanja},
+    keyboard::{Layout, compose},
+    romanization::Scheme,
+    syllable::Syllable,
+    text::Text,
+};
 
 #[derive(Debug, Default)]
 pub struct HangulResult {
     pos: (u16, u16, u16),
     overflow: String,
+    /// Syllable being typed with a keyboard layout
     syllable: Syllable,
-    str: Hangul,
+    /// Readings of the romanized word being typed, best first
+    readings: Vec<Hangul>,
+    reading: usize,
+    /// Hanja of the word being typed
+    word_hanja: Option<String>,
+    literal: Option<String>,
+    str: Text,
+    /// `str` with the Hanja it was typed as, e.g. "韓國 말"
+    hanja: String,
     parser: HangulParser,
+    layouts: Vec<Layout>,
+    /// Index into `layouts`, `None` when reading romanization
+    layout: Option<usize>,
 }
 impl HangulResult {
-    pub fn new(pos: (u16, u16, u16)) -> Self {
+    /// Input starting with this is taken verbatim
+    pub const LITERAL: char = '"';
+
+    pub fn new(
+        pos: (u16, u16, u16),
+        parser: &HangulParser,
+        layouts: &[Layout],
+    ) -> Self {
         Self {
             pos,
+            parser: parser.clone(),
+            layouts: layouts.to_vec(),
             ..Default::default()
         }
     }
 
-    pub fn syllable(&self) -> &Syllable {
-        &self.syllable
+    pub fn layout(&self) -> Option<&Layout> {
+        self.layout.and_then(|i| self.layouts.get(i))
+    }
+
+    /// Name of the active input mode
+    pub fn mode_name(&self) -> &str {
+        match self.layout() {
+            Some(l) => l.name(),
+            None => self.scheme().name(),
+        }
+    }
+
+    /// Cycles from romanization through all keyboard layouts, the
+    /// pending syllable is submitted first
+    pub fn next_mode(&mut self) {
+        self.push();
+        self.overflow = String::new();
+        self.syllable = Syllable::default();
+        self.readings = vec![];
+        self.layout = match self.layout {
+            None if !self.layouts.is_empty() => Some(0),
+            Some(i) if i + 1 < self.layouts.len() => Some(i + 1),
+            _ => None,
+        };
+    }
+
+    /// Types `key` with the active keyboard layout, returns `false` if
+    /// the key is not part of the layout
+    pub fn push_key(&mut self, key: char) -> bool {
+        let key = match self.layout().and_then(|l| l.get(key)) {
+            Some(k) => k,
+            None => return false,
+        };
+        if let Some(done) = compose(&mut self.syllable, key) {
+            self.push_syllable(done);
+        }
+        true
+    }
+
+    /// Removes the last jamo of the syllable being typed, otherwise the
+    /// last submitted syllable
+    pub fn pop_key(&mut self) {
+        if self.syllable.is_empty() {
+            self.pop();
+        } else {
+            self.syllable.pop();
+        }
+    }
+
+    pub fn scheme(&self) -> Scheme {
+        self.parser.scheme()
+    }
+
+    /// Syllable being typed, the last of the chosen reading when
+    /// reading romanization
+    pub fn syllable(&self) -> Syllable {
+        match self.layout {
+            Some(_) => self.syllable,
+            None => self
+                .reading()
+                .and_then(|h| h.last().copied())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn readings(&self) -> &[Hangul] {
+        &self.readings
     }
 
-    pub fn str(&self) -> &Hangul {
+    /// The chosen reading of the romanized word being typed
+    pub fn reading(&self) -> Option<&Hangul> {
+        self.readings.get(self.reading)
+    }
+
+    pub fn next_reading(&mut self) {
+        if !self.readings.is_empty() {
+            self.reading = (self.reading + 1) % self.readings.len();
+        }
+    }
+
+    pub fn previous_reading(&mut self) {
+        if !self.readings.is_empty() {
+            self.reading =
+                (self.reading + self.readings.len() - 1) % self.readings.len();
+        }
+    }
+
+    pub fn str(&self) -> &Text {
         &self.str
     }
 
+    /// Submitted text as typed if any of it was typed in Hanja
+    pub fn hanja(&self) -> Option<&str> {
+        match self.hanja.chars().any(is_hanja) {
+            true => Some(&self.hanja),
+            false => None,
+        }
+    }
+
     pub fn overflow(&self) -> &String {
         &self.overflow
     }
@@ -40,23 +160,95 @@ impl HangulResult {
     }
 
     pub fn push(&mut self) {
-        self.str.push(self.syllable.clone());
+        match self.literal.take() {
+            Some(l) => self.push_literal(&l),
+            None if self.layout.is_some() => {
+                if !self.syllable.is_empty() {
+                    self.push_syllable(self.syllable);
+                }
+                self.syllable = Syllable::default();
+            }
+            None => {
+                let reading = self.reading().cloned().unwrap_or_default();
+                let hanja = self.word_hanja.take().unwrap_or_default();
+                let mut hanja = hanja.chars();
+                for syllable in reading.iter() {
+                    self.str.push_syllable(*syllable);
+                    self.hanja.push(hanja.next().unwrap_or(syllable.into()));
+                }
+                self.readings = vec![];
+            }
+        }
     }
 
+    pub fn push_space(&mut self) {
+        self.push_literal(" ");
+    }
+
+    pub fn push_literal(&mut self, literal: &str) {
+        self.str.push_literal(literal);
+        self.hanja.push_str(literal);
+    }
+
+    /// Removes the last syllable or literal character
     pub fn pop(&mut self) {
-        self.str.pop();
+        self.str.pop_back();
+        self.hanja.pop();
     }
 
     pub fn set_rr(&mut self, rr: &str) {
+        if let Some(literal) = rr.strip_prefix(Self::LITERAL) {
+            self.literal = Some(literal.into());
+            self.readings = vec![];
+            self.overflow = String::new();
+            return;
+        }
+        self.literal = None;
+        self.reading = 0;
+        // A word typed in Hanja is read by its Hangul readings
+        let word: String = rr.chars().take_while(|c| is_hanja(*c)).collect();
+        if !word.is_empty() {
+            self.readings = hanja::read(&word);
+            self.overflow = match self.readings.is_empty() {
+                true => rr.into(),
+                false => rr[word.len()..].into(),
+            };
+            self.word_hanja = Some(word);
+            return;
+        }
+        self.word_hanja = None;
         let overflow;
-        (self.syllable, overflow) = self.parser.parse_syllable(rr);
+        (self.readings, overflow) = self.parser.segment(rr);
         self.overflow = overflow.into();
     }
 
     pub fn clear(&mut self) {
         self.overflow = String::new();
         self.syllable = Syllable::default();
-        self.str = Hangul::default();
+        self.readings = vec![];
+        self.word_hanja = None;
+        self.literal = None;
+        self.str = Text::default();
+        self.hanja = String::new();
+    }
+
+    fn push_syllable(&mut self, syllable: Syllable) {
+        self.str.push_syllable(syllable);
+        self.hanja.push(syllable.into());
+    }
+
+    /// Typed Hanja without a reading, which stops the word being read
+    fn unknown_hanja(&self) -> Option<char> {
+        self.word_hanja.as_deref().and_then(hanja::unknown)
+    }
+
+    /// Not yet submitted word, syllable or literal
+    fn pending(&self) -> String {
+        match &self.literal {
+            Some(l) => l.clone(),
+            None if self.layout.is_some() => self.syllable.to_string(),
+            None => self.reading().map(|h| h.to_string()).unwrap_or_default(),
+        }
     }
 }
 impl Block for HangulResult {
@@ -66,7 +258,18 @@ impl Block for HangulResult {
 
     fn rel_line(&self, i: u16) -> Option<String> {
         match i {
-            0 => Some(format!("{}{}", self.str, self.syllable)),
+            0 => Some(format!("{}{}", self.str, self.pending())),
+            // The bundled Hanja readings are far from complete
+            1 if let Some(c) = self.unknown_hanja() => Some(format!(
+                "No reading of {}, only {} common Hanja are known",
+                c,
+                hanja::known()
+            )),
+            1 if self.is_empty() => Some("".into()),
+            1 => Some(match self.hanja() {
+                Some(h) => format!("[{}] {}", self.str.pronounce(), h),
+                None => format!("[{}]", self.str.pronounce()),
+            }),
             _ => None,
         }
     }
@@ -77,7 +280,7 @@ impl Block for HangulResult {
         }
         // utf-8 byte count
         let str = format!("{}", self.str);
-        let ex = format!("{}", self.syllable);
+        let ex = self.pending();
         let style = ContentStyle {
             attributes: Attributes::none().with(Attribute::RapidBlink),
             ..Default::default()
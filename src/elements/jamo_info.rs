@@ -1,10 +1,15 @@
 use terminal::traits::Block;
 
+use crate::romanization::Scheme;
+
 #[derive(Debug, Default, Clone)]
-pub struct JamoInfo((u16, u16, u16));
+pub struct JamoInfo {
+    pos: (u16, u16, u16),
+    scheme: Scheme,
+}
 impl JamoInfo {
     // Assuming Jamo take 'two' slots
-    const LINES: [&str; 15] = [
+    const RR_LINES: [&str; 15] = [
         " Initials/Finals        Medials         ",
         " ㄱ g                   ㅏ a            ",
         " ㄴ n                   ㅐ ae           ",
@@ -21,16 +26,59 @@ impl JamoInfo {
         " ㅍ p                   ㅡ eu           ",
         " ㅎ h                   ㅣ i            ",
     ];
-    pub fn new(pos: (u16, u16, u16)) -> Self {
-        Self(pos)
+    const MR_LINES: [&str; 15] = [
+        " Initials/Finals        Medials         ",
+        " ㄱ k g                 ㅏ a            ",
+        " ㄴ n                   ㅐ ae           ",
+        " ㄷ t d                 ㅑ ya           ",
+        " ㄹ r l                 ㅒ yae          ",
+        " ㅁ m                   ㅓ ŏ eo         ",
+        " ㅂ p b                 ㅔ e            ",
+        " ㅅ s sh                ㅕ yŏ yeo       ",
+        " ㅇ ng                  ㅖ ye           ",
+        " ㅈ ch j                ㅗ o            ",
+        " ㅊ ch'                 ㅛ yo           ",
+        " ㅋ k'                  ㅜ u            ",
+        " ㅌ t'                  ㅠ yu           ",
+        " ㅍ p'                  ㅡ ŭ eu         ",
+        " ㅎ h                   ㅣ i            ",
+    ];
+    const YALE_LINES: [&str; 15] = [
+        " Initials/Finals        Medials         ",
+        " ㄱ k   ㅋ kh           ㅏ a    ㅐ ay   ",
+        " ㄴ n                   ㅑ ya   ㅒ yay  ",
+        " ㄷ t   ㅌ th           ㅓ e    ㅔ ey   ",
+        " ㄹ l                   ㅕ ye   ㅖ yey  ",
+        " ㅁ m                   ㅗ o    ㅚ oy   ",
+        " ㅂ p   ㅍ ph           ㅘ wa   ㅙ way  ",
+        " ㅅ s                   ㅛ yo           ",
+        " ㅇ ng                  ㅜ wu   ㅟ wi   ",
+        " ㅈ c   ㅊ ch           ㅝ we   ㅞ wey  ",
+        " ㄲ kk  ㄸ tt           ㅠ yu           ",
+        " ㅃ pp  ㅆ ss           ㅡ u    ㅢ uy   ",
+        " ㅉ cc                  ㅣ i            ",
+        "                                        ",
+        " Syllable break: .                      ",
+    ];
+
+    pub fn new(pos: (u16, u16, u16), scheme: Scheme) -> Self {
+        Self { pos, scheme }
+    }
+
+    fn lines(&self) -> &[&str] {
+        match self.scheme {
+            Scheme::Rr => &Self::RR_LINES,
+            Scheme::Mr => &Self::MR_LINES,
+            Scheme::Yale => &Self::YALE_LINES,
+        }
     }
 }
 impl Block for JamoInfo {
     fn pos(&self) -> (u16, u16, u16) {
-        self.0
+        self.pos
     }
 
     fn rel_line(&self, i: u16) -> Option<String> {
-        Self::LINES.get(i as usize).map(|&s| s.into())
+        self.lines().get(i as usize).map(|&s| s.into())
     }
 }
@@ -0,0 +1,118 @@
+use std::{iter, ops::Range};
+
+use terminal::{
+    code::TerminalCode,
+    event::{KeyCode, KeyEvent, KeyEventKind},
+    style::ContentStyle,
+    traits::{Block, Input},
+};
+
+use crate::{
+    conjugation::{Lemma, lemmatize},
+    elements::ChoiceList,
+    text::Text,
+};
+
+macro_rules! enter {
+    () => {
+        KeyEvent {
+            code: KeyCode::Enter,
+            kind: KeyEventKind::Press,
+            ..
+        }
+    };
+}
+
+/// ## Lemma Choice
+/// Lists the dictionary forms a seen word may have been conjugated from.
+/// `on_choose` gets the chosen one, or `None` to keep the word as seen.
+pub struct LemmaChoice<F> {
+    pos: (u16, u16, u16),
+    seen: Text,
+    /// Lemmas of `seen`, then `None` for `seen` itself
+    lemmas: ChoiceList<Option<Lemma>>,
+    on_choose: F,
+}
+impl<F> LemmaChoice<F>
+where
+    F: Fn(Option<&Lemma>) -> TerminalCode,
+{
+    pub fn new(pos: (u16, u16, u16), on_choose: F) -> Self {
+        Self {
+            pos,
+            seen: Text::default(),
+            // Every lemma is shown
+            lemmas: ChoiceList::new(usize::MAX),
+            on_choose,
+        }
+    }
+
+    /// Looks up the dictionary forms of `seen`, `false` if there are
+    /// none
+    pub fn set_seen(&mut self, seen: Text) -> bool {
+        let lemmas = lemmatize(&seen);
+        let found = !lemmas.is_empty();
+        self.lemmas.set_items(
+            lemmas
+                .into_iter()
+                .map(Some)
+                .chain(iter::once(None))
+                .collect(),
+        );
+        self.seen = seen;
+        found
+    }
+}
+impl<F> Block for LemmaChoice<F> {
+    fn pos(&self) -> (u16, u16, u16) {
+        self.pos
+    }
+
+    fn rel_line(&self, i: u16) -> Option<String> {
+        let i = i as usize;
+        match i {
+            0 => Some(format!("{} looks conjugated, save it as:", self.seen)),
+            1 => Some("".into()),
+            _ => self.lemmas.shown(i - 2).map(|l| match l {
+                Some(l) => format!("{}  ({})", l.dictionary, l.ending),
+                None => format!("{}  (as typed)", self.seen),
+            }),
+        }
+    }
+
+    fn style_line(&self, i: u16) -> Vec<(Range<usize>, ContentStyle)> {
+        match (i as usize).checked_sub(2) {
+            Some(i) => self.lemmas.style_line(i),
+            None => vec![],
+        }
+    }
+}
+impl<F> Input for LemmaChoice<F>
+where
+    F: Fn(Option<&Lemma>) -> TerminalCode,
+{
+    fn feed(&mut self, key: KeyEvent) -> TerminalCode {
+        match key {
+            enter!() => {
+                (self.on_choose)(self.lemmas.chosen().and_then(Option::as_ref))
+            }
+            _ => self.lemmas.feed(key),
+        }
+    }
+
+    fn rel_cursor_pos(&self) -> Option<(u16, u16)> {
+        None
+    }
+
+    fn input_pos(&self) -> (u16, u16) {
+        (self.pos.0, self.pos.1)
+    }
+
+    fn focus(&mut self) {
+        self.lemmas.focus()
+    }
+
+    fn unfocus(&mut self) {
+        self.lemmas.unfocus()
+    }
+}
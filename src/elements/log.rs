@@ -1,7 +1,11 @@
-use std::{cmp::Ordering, env, io, ops::Range, path::PathBuf};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BTreeMap,
+    io,
+    ops::Range,
+    path::{Path, PathBuf},
+};
 
-use csv::{ReaderBuilder, WriterBuilder};
-use serde::{Deserialize, Serialize};
 use terminal::{
     code::TerminalCode,
     elements::TextLine,
@@ -11,63 +15,282 @@ use terminal::{
     traits::{Block, Input},
 };
 
-use crate::{ext::OrderedMap, hangul::Hangul};
+use crate::{
+    backup,
+    collation::Collation,
+    ext::{OrderedMap, now},
+    hangul::Hangul,
+    pattern::Pattern,
+    romanization::Scheme,
+    schema::{self, PartOfSpeech, Row},
+    search::{DescriptionIndex, jamo_distance, match_ranges, max_distance},
+    text::{Segment, Text},
+};
+
+const SUPERSCRIPTS: &str = "⁰¹²³⁴⁵⁶⁷⁸⁹";
+
+/// Hangul of an entry and which of its senses it is, from 1, e.g. `배`
+/// the boat and `배` the pear
+pub type Sense = (Text, usize);
+
+/// ## Details
+/// What is known of an entry besides its Hangul and description
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Details {
+    pub part_of_speech: Option<PartOfSpeech>,
+    pub tags: Vec<String>,
+    pub example: String,
+    pub notes: String,
+    /// Seconds since the Unix epoch, 0 if unknown
+    pub created: u64,
+    /// Seconds since the Unix epoch, 0 if unknown
+    pub modified: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    /// Empty unless the entry was typed in Hanja
+    hanja: String,
+    /// Conjugated form the entry was met as, empty if it was typed in
+    /// its dictionary form
+    seen: String,
+    description: TextLine,
+    details: Details,
+}
+
+/// ## Order
+/// Sorting of the log while it is not filtered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Order {
+    Collation(Collation),
+    Added,
+    Description,
+}
+impl Default for Order {
+    fn default() -> Self {
+        Order::Collation(Collation::default())
+    }
+}
+impl Order {
+    fn all() -> Vec<Order> {
+        Collation::all()
+            .map(Order::Collation)
+            .into_iter()
+            .chain([Order::Added, Order::Description])
+            .collect()
+    }
+
+    fn name(self) -> String {
+        match self {
+            Order::Collation(c) => format!("{} order", c.name()),
+            Order::Added => "newest first".into(),
+            Order::Description => "description".into(),
+        }
+    }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct Row {
-    hangul: Hangul,
-    description: String,
+    fn next(self) -> Order {
+        let all = Self::all();
+        let i = all.iter().position(|o| *o == self).unwrap_or(0);
+        all[(i + 1) % all.len()]
+    }
 }
 #[derive(Debug, Clone)]
 pub struct Log {
+    /// File the entries are saved to, after every change
+    path: PathBuf,
+    /// Why the last change could not be saved
+    save_error: Option<String>,
+    /// Why `path` could not be read, it is then never saved over
+    read_error: Option<String>,
     pos: (u16, u16, u16),
     input_pos: (u16, u16),
     width: u16,
     height: u16,
-    entries: OrderedMap<Hangul, TextLine>,
+    entries: OrderedMap<Sense, Entry>,
+    descriptions: DescriptionIndex<Sense>,
+    /// Search over the descriptions, every entry is shown if empty
+    filter: String,
+    /// The filter read as a pattern over the Hangul of entries
+    pattern: Option<Pattern>,
+    /// Indices of the shown entries, in order
+    visible: Vec<usize>,
+    order: Order,
     index: usize,
     focused: bool,
+    scheme: Scheme,
 }
 impl Log {
-    pub const ENTRY_HEIGHT: usize = 3; // Hangul \ Description \ Br
+    // Hangul \ Pronunciation \ Description \ Br
+    pub const ENTRY_HEIGHT: usize = 4;
 
+    /// Log saved to `path`, loading the entries already there
     pub fn new(
+        path: PathBuf,
         pos: (u16, u16, u16),
         width: u16,
         height: u16,
     ) -> io::Result<Self> {
-        let entries: OrderedMap<Hangul, TextLine> =
-            match Self::get_csv_records() {
-                Ok(r) => r
-                    .into_iter()
-                    .map(|row| {
-                        (
-                            row.hangul,
-                            Self::new_description(width, row.description),
-                        )
-                    })
-                    .collect(),
-                Err(e) => {
-                    log::warn!("{}", e);
-                    Default::default()
-                }
-            };
-        Ok(Self {
+        let mut log = Self {
+            path,
             pos,
             input_pos: (pos.0, pos.1),
             width,
             height: height.saturating_sub(2),
             index: 0,
-            entries,
+            visible: vec![],
+            entries: OrderedMap::new(),
+            descriptions: DescriptionIndex::default(),
+            filter: String::new(),
+            pattern: None,
+            order: Order::default(),
             focused: false,
-        })
+            scheme: Scheme::default(),
+            save_error: None,
+            read_error: None,
+        };
+        log.back_up();
+        log.load();
+        Ok(log)
+    }
+
+    /// Saves the log and continues with the one at `path`, e.g. of
+    /// another deck
+    pub fn open(&mut self, path: PathBuf) -> io::Result<()> {
+        self.save_if_read()?;
+        self.path = path;
+        self.back_up();
+        self.load();
+        Ok(())
+    }
+
+    /// Replaces the entries with those of the backup at `from`, backing
+    /// up the current ones first so the restore can be undone
+    pub fn restore(&mut self, from: &Path) -> io::Result<()> {
+        let rows = schema::read(from)?;
+        self.save_if_read()?;
+        backup::rotate(&self.path)?;
+        schema::write(&self.path, rows)?;
+        self.load();
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Saves to `path` from now on, e.g. after the file was moved
+    pub fn set_path(&mut self, path: PathBuf) {
+        self.path = path;
+    }
+
+    fn load(&mut self) {
+        self.read_error = None;
+        let rows = match schema::read(&self.path) {
+            Ok(r) => r,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                log::warn!("{}", e);
+                Default::default()
+            }
+            Err(e) => {
+                log::error!("Reading {:?} failed: {}", self.path, e);
+                self.read_error = Some(e.to_string());
+                Default::default()
+            }
+        };
+        // Senses are numbered in the order they were written
+        let mut entries = OrderedMap::new();
+        let mut senses = BTreeMap::<Text, usize>::new();
+        for row in rows {
+            let sense = senses.entry(row.hangul.clone()).or_default();
+            *sense += 1;
+            let details = Details {
+                part_of_speech: row.part_of_speech,
+                tags: row
+                    .tags
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .map(String::from)
+                    .collect(),
+                example: row.example,
+                notes: row.notes,
+                created: row.created,
+                modified: row.modified,
+            };
+            entries.insert(
+                (row.hangul, *sense),
+                Self::new_entry(
+                    self.width,
+                    row.hanja,
+                    row.seen,
+                    row.description,
+                    details,
+                ),
+            );
+        }
+        let mut descriptions = DescriptionIndex::default();
+        for (h, e) in entries.iter() {
+            descriptions.insert(h, e.description.value());
+        }
+        self.entries = entries;
+        self.descriptions = descriptions;
+        self.filter.clear();
+        self.pattern = None;
+        self.index = 0;
+        self.save_error = None;
+        self.refresh_view();
+    }
+
+    /// Keeps the log as it was opened, see [`backup::rotate`]
+    fn back_up(&self) {
+        if let Err(e) = backup::rotate(&self.path) {
+            log::warn!("Backing up {:?} failed: {}", self.path, e);
+        }
+    }
+
+    /// Saves a change right away, so a crash loses nothing
+    fn autosave(&mut self) {
+        self.save_error = match self.save() {
+            Ok(()) => None,
+            Err(e) => {
+                log::error!("Saving {:?} failed: {}", self.path, e);
+                Some(e.to_string())
+            }
+        };
     }
 
+    /// Saves unless `path` could not be read, which is left as it was,
+    /// e.g. when leaving the log, the user having been told changes are
+    /// not being saved
+    pub fn save_if_read(&self) -> io::Result<()> {
+        match self.read_error {
+            Some(_) => Ok(()),
+            None => self.save(),
+        }
+    }
+
+    /// Writes the entries to `path`, unless it could not be read, which
+    /// is kept as it was for a [`Log::restore`] or to be fixed by hand
     pub fn save(&self) -> io::Result<()> {
-        Self::set_csv_records(self.entries.iter().map(|(h, d)| Row {
+        if self.read_error.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the file could not be read, changes are not being saved",
+            ));
+        }
+        let rows = self.entries.iter().map(|((h, _), e)| Row {
             hangul: h.clone(),
-            description: d.value().to_string(),
-        }))
+            hanja: e.hanja.clone(),
+            seen: e.seen.clone(),
+            description: e.description.value().to_string(),
+            part_of_speech: e.details.part_of_speech,
+            tags: e.details.tags.join(", "),
+            example: e.details.example.clone(),
+            notes: e.details.notes.clone(),
+            created: e.details.created,
+            modified: e.details.modified,
+        });
+        schema::write(&self.path, rows)
     }
 
     pub fn with_input_pos(&mut self, pos: (u16, u16)) -> &mut Self {
@@ -75,17 +298,54 @@ impl Log {
         self
     }
 
+    pub fn with_scheme(&mut self, scheme: Scheme) -> &mut Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Adds `hangul` as a new sense, unless it has a sense of the same
+    /// Hanja, or with neither Hanja the same description, which is
+    /// replaced instead
     pub fn insert_entry(
         &mut self,
-        key: Hangul,
+        hangul: Text,
+        hanja: String,
+        seen: String,
         description: String,
-    ) -> Option<(Hangul, String)> {
+    ) -> Option<(Sense, String)> {
+        let senses = self.senses(&hangul);
+        let same = senses.iter().find(|(k, _)| {
+            let e = &self.entries.get_entry(k).expect("Logic error!").1;
+            match hanja.is_empty() && e.hanja.is_empty() {
+                true => e.description.value() == description,
+                false => e.hanja == hanja,
+            }
+        });
+        let key = match same {
+            Some((k, _)) => k.clone(),
+            None => (hangul, senses.len() + 1),
+        };
         let ordering = self.current_entry().map(|(k, _)| key.cmp(k));
+        self.descriptions.insert(&key, &description);
+        // A replaced entry keeps its details
+        let details = match self.entries.get_entry(&key) {
+            Some((_, e)) => Details {
+                modified: now(),
+                ..e.details.clone()
+            },
+            None => Details {
+                created: now(),
+                modified: now(),
+                ..Default::default()
+            },
+        };
+        let entry =
+            Self::new_entry(self.width, hanja, seen, description, details);
         let replaced = self
             .entries
-            .insert(key, Self::new_description(self.width, description))
-            .map(|(k, v)| (k, v.value().to_string()));
-        match (replaced, ordering) {
+            .insert(key, entry)
+            .map(|(k, v)| (k, v.description.value().to_string()));
+        let ret = match (replaced, ordering) {
             (None, Some(Ordering::Less)) => {
                 self.index += 1;
                 None
@@ -95,43 +355,313 @@ impl Log {
                 ret
             }
             (ret, _) => ret,
-        }
+        };
+        self.refresh_view();
+        self.autosave();
+        ret
     }
 
-    pub fn remove_entry(&mut self, key: &Hangul) {
+    pub fn remove_entry(&mut self, key: &Sense) {
         let current = self.current_entry().map(|c| c.0.clone());
         match (current, self.entries.remove(key)) {
-            (Some(current), Some(_)) if current < *key => {
+            // Entries after the removed one move up
+            (Some(current), Some(_)) if current > *key => {
                 self.index -= 1;
             }
-            _ => (),
+            _ => {
+                self.index =
+                    self.index.min(self.entries.len().saturating_sub(1))
+            }
+        }
+        self.descriptions.remove(key);
+        // Later senses move up, keeping their place in the log
+        let (hangul, sense) = key;
+        for (k, _) in self.senses(hangul) {
+            if k.1 > *sense
+                && let Some((_, e)) = self.entries.remove(&k)
+            {
+                let renumbered = (k.0.clone(), k.1 - 1);
+                self.descriptions.remove(&k);
+                self.descriptions.insert(&renumbered, e.description.value());
+                self.entries.insert(renumbered, e);
+            }
+        }
+        self.refresh_view();
+        self.autosave();
+    }
+
+    /// Every sense of `hangul` with its description
+    pub fn senses(&self, hangul: &Text) -> Vec<(Sense, String)> {
+        self.entries
+            .iter()
+            .filter(|((h, _), _)| h == hangul)
+            .map(|(k, e)| (k.clone(), e.description.value().to_string()))
+            .collect()
+    }
+
+    /// Only shows entries matching `query` over their description, see
+    /// [`DescriptionIndex::search`], after those it matches as a
+    /// [`Pattern`] over their Hangul if it is one
+    pub fn set_filter(&mut self, query: &str) {
+        self.filter = query.into();
+        self.pattern = Pattern::try_from(query).ok();
+        self.refresh_view();
+    }
+
+    fn refresh_view(&mut self) {
+        let found = match &self.pattern {
+            _ if self.filter.trim().is_empty() => {
+                self.visible = self.sorted();
+                vec![]
+            }
+            // Hangul may be a pattern as well as a description
+            Some(pattern) => {
+                let mut found = self
+                    .find_pattern(pattern)
+                    .into_iter()
+                    .map(|(h, _)| h)
+                    .collect::<Vec<_>>();
+                for key in self.descriptions.search(&self.filter) {
+                    if !found.contains(&key) {
+                        found.push(key);
+                    }
+                }
+                found
+            }
+            None => self.descriptions.search(&self.filter),
+        };
+        if !self.filter.trim().is_empty() {
+            self.visible = found
+                .iter()
+                .filter_map(|k| self.entries.key_index(k))
+                .collect();
+        }
+        if !self.visible.contains(&self.index)
+            && let Some(first) = self.visible.first()
+        {
+            self.index = *first;
+        }
+    }
+
+    /// Indices of every entry in the chosen order
+    fn sorted(&self) -> Vec<usize> {
+        let mut ret = (0..self.entries.len()).collect::<Vec<_>>();
+        let entry = |i: &usize| &self.entries[*i];
+        match self.order {
+            Order::Collation(c) => {
+                ret.sort_by_cached_key(|i| c.key(&entry(i).0.0))
+            }
+            Order::Added => {
+                ret.sort_by_key(|i| Reverse(entry(i).1.details.created))
+            }
+            Order::Description => ret.sort_by_cached_key(|i| {
+                entry(i).1.description.value().to_lowercase()
+            }),
+        }
+        ret
+    }
+
+    /// Current search over the descriptions
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Selects the `i`th entry, dropping the filter if it hides it
+    fn show(&mut self, i: usize) {
+        self.index = i;
+        if !self.visible.contains(&i) {
+            self.set_filter("");
+        }
+    }
+
+    /// Position of the selected entry among the shown ones
+    fn view_index(&self) -> usize {
+        self.visible
+            .iter()
+            .position(|i| *i == self.index)
+            .unwrap_or(0)
+    }
+
+    fn visible_entry(&self, i: usize) -> Option<&(Sense, Entry)> {
+        self.visible.get(i).and_then(|i| self.entries.get(*i))
+    }
+
+    /// Moves to the first sense of `hangul`
+    pub fn index_at(&mut self, hangul: &Text) -> bool {
+        self.index_at_sense(&(hangul.clone(), 1))
+    }
+
+    pub fn index_at_sense(&mut self, key: &Sense) -> bool {
+        match self.entries.key_index(key) {
+            Some(i) => {
+                self.show(i);
+                true
+            }
+            None => false,
         }
     }
 
-    pub fn index_at(&mut self, key: &Hangul) -> bool {
-        let found =
-            self.entries.iter().enumerate().find_map(|(i, (k, _))| {
-                if *k == *key { Some(i) } else { None }
-            });
-        match found {
+    /// Moves to the first entry written with `hanja`
+    pub fn index_at_hanja(&mut self, hanja: &str) -> bool {
+        match self
+            .entries
+            .iter()
+            .position(|(_, e)| e.hanja.contains(hanja))
+        {
             Some(i) => {
-                self.index = i;
+                self.show(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Entries within a few jamo edits of `query`, closest first, with
+    /// their description
+    pub fn fuzzy_find(&self, query: &Text) -> Vec<(Sense, String)> {
+        let max = max_distance(query);
+        let mut found = self
+            .entries
+            .iter()
+            .filter_map(|(h, e)| {
+                let (hangul, _) = h;
+                let distance = jamo_distance(query, hangul);
+                (distance <= max).then_some((distance, h, e))
+            })
+            .collect::<Vec<_>>();
+        found.sort_by_key(|(distance, _, _)| *distance);
+        found
+            .into_iter()
+            .map(|(_, h, e)| (h.clone(), e.description.value().to_string()))
+            .collect()
+    }
+
+    /// Entries whose syllables start with the initials of `query`
+    /// (초성), e.g. `ㅎㄱ` finds `한국` and `학교`. Entries starting with
+    /// them come first.
+    pub fn find_initials(&self, query: &Hangul) -> Vec<(Sense, String)> {
+        let query = query.initials();
+        let mut found = self
+            .entries
+            .iter()
+            .filter_map(|(h, e)| {
+                let initials =
+                    h.0.iter()
+                        .filter_map(|s| match s {
+                            Segment::Hangul(h) => Some(h.initials()),
+                            Segment::Literal(_) => None,
+                        })
+                        .flatten()
+                        .collect::<Vec<_>>();
+                let at =
+                    initials.windows(query.len()).position(|w| w == query)?;
+                Some((at > 0, h, e))
+            })
+            .collect::<Vec<_>>();
+        found.sort_by_key(|(inside, _, _)| *inside);
+        found
+            .into_iter()
+            .map(|(_, h, e)| (h.clone(), e.description.value().to_string()))
+            .collect()
+    }
+
+    /// Entries with syllables matching `pattern`, whole matches first,
+    /// then those starting with one
+    pub fn find_pattern(&self, pattern: &Pattern) -> Vec<(Sense, String)> {
+        let mut found = self
+            .entries
+            .iter()
+            .filter_map(|(h, e)| {
+                let (at, whole) = h.0.iter().find_map(|s| match s {
+                    Segment::Hangul(hangul) => pattern.find(hangul).map(|at| {
+                        (at, h.0.len() == 1 && pattern.matches(hangul))
+                    }),
+                    Segment::Literal(_) => None,
+                })?;
+                Some(((!whole, at > 0), h, e))
+            })
+            .collect::<Vec<_>>();
+        found.sort_by_key(|(rank, _, _)| *rank);
+        found
+            .into_iter()
+            .map(|(_, h, e)| (h.clone(), e.description.value().to_string()))
+            .collect()
+    }
+
+    /// Hangul of every entry with a sense of one of `parts` of speech,
+    /// in order, once however many senses it has
+    pub fn keys_of(&self, parts: &[PartOfSpeech]) -> Vec<&Text> {
+        let mut ret: Vec<&Text> = vec![];
+        for ((h, _), e) in self.entries.iter() {
+            if e.details.part_of_speech.is_some_and(|p| parts.contains(&p))
+                && ret.last() != Some(&h)
+            {
+                ret.push(h);
+            }
+        }
+        ret
+    }
+
+    /// Details of the selected entry
+    pub fn current_details(&self) -> Option<&Details> {
+        self.current_entry().map(|(_, e)| &e.details)
+    }
+
+    /// Description of the selected entry
+    pub fn current_description(&self) -> Option<&str> {
+        self.current_entry().map(|(_, e)| e.description.value())
+    }
+
+    /// Replaces the details of the entry of `key`
+    pub fn set_details(&mut self, key: &Sense, details: Details) -> bool {
+        match self.entries.value_mut(key) {
+            Some(e) => {
+                e.details = Details {
+                    modified: now(),
+                    ..details
+                };
+                self.autosave();
                 true
             }
             None => false,
         }
     }
 
-    pub fn current_entry(&self) -> Option<&(Hangul, TextLine)> {
+    /// Hangul of the selected entry
+    pub fn current_hangul(&self) -> Option<&Text> {
+        self.current_sense().map(|(h, _)| h)
+    }
+
+    /// Hangul and sense of the selected entry
+    pub fn current_sense(&self) -> Option<&Sense> {
+        self.current_entry().map(|(k, _)| k)
+    }
+
+    /// Superscript number of the sense of `key`, empty for words with a
+    /// single sense, e.g. `²` in `배²`
+    fn sense_mark(&self, key: &Sense) -> String {
+        let (hangul, sense) = key;
+        if *sense == 1 && self.entries.key_index(&(hangul.clone(), 2)).is_none()
+        {
+            return String::new();
+        }
+        sense
+            .to_string()
+            .chars()
+            .filter_map(|c| SUPERSCRIPTS.chars().nth(c.to_digit(10)? as usize))
+            .collect()
+    }
+
+    fn current_entry(&self) -> Option<&(Sense, Entry)> {
         self.entries.get(self.index)
     }
 
     pub fn line_index(&self) -> usize {
-        self.index * Self::ENTRY_HEIGHT
+        self.view_index() * Self::ENTRY_HEIGHT
     }
 
     pub fn line_count(&self) -> usize {
-        self.entries.len() * Self::ENTRY_HEIGHT
+        self.visible.len() * Self::ENTRY_HEIGHT
     }
 
     fn display_range(&self) -> Range<usize> {
@@ -139,46 +669,26 @@ impl Log {
         let len = self.line_count().min(self.height as usize);
         saturate_range(
             range_with_mid(index as isize, len as isize),
-            0..self.line_count() as usize,
+            0..self.line_count(),
         )
     }
 
-    fn get_csv_records() -> io::Result<Vec<Row>> {
-        let csv_path = Self::csv_path()?;
-        let mut rdr =
-            ReaderBuilder::new().delimiter(b';').from_path(csv_path)?;
-
-        Ok(rdr
-            .deserialize()
-            .filter_map(|res| res.ok())
-            .collect::<Vec<_>>())
-    }
-
-    fn set_csv_records(
-        entries: impl IntoIterator<Item = Row>,
-    ) -> io::Result<()> {
-        let csv_path = Self::csv_path()?;
-        let mut wtr = WriterBuilder::new()
-            .delimiter(b';')
-            .has_headers(true)
-            .from_path(csv_path)?;
-        for entry in entries {
-            wtr.serialize(entry)?;
+    fn new_entry(
+        width: u16,
+        hanja: String,
+        seen: String,
+        description: String,
+        details: Details,
+    ) -> Entry {
+        Entry {
+            hanja,
+            seen,
+            details,
+            description: TextLine::default()
+                .with_width(width)
+                .with_value(description)
+                .clone(),
         }
-        Ok(())
-    }
-
-    fn csv_path() -> io::Result<PathBuf> {
-        let mut csv_file = env::current_dir()?.into_os_string();
-        csv_file.push("/hangul-log.csv");
-        Ok(csv_file.into())
-    }
-
-    fn new_description(width: u16, text: String) -> TextLine {
-        TextLine::default()
-            .with_width(width)
-            .with_value(text)
-            .clone()
     }
 }
 impl Block for Log {
@@ -189,8 +699,48 @@ impl Block for Log {
     fn rel_line(&self, i: u16) -> Option<String> {
         // Header
         match i {
-            0 => return Some("Log:".into()),
-            1 => return Some("".into()),
+            0 if let Some(e) = &self.save_error => {
+                return Some(format!("Log:  not saved, {}", e));
+            }
+            0 if let Some(e) = &self.read_error => {
+                return Some(format!("Log:  not read, {}", e));
+            }
+            0 if self.filter.trim().is_empty() => {
+                return Some("Log:  (Enter to conjugate, e to edit)".into());
+            }
+            0 => {
+                return Some(format!(
+                    "Log:  {} of {} match",
+                    self.visible.len(),
+                    self.entries.len()
+                ));
+            }
+            // Syllables fitting a pattern, e.g. 갈날달… for ?ㅏㄹ
+            1 => {
+                let fits = self.pattern.as_ref().and_then(Pattern::syllable);
+                let fits = match fits {
+                    _ if self.filter.trim().is_empty() => {
+                        return Some(format!(
+                            "Sorted by {}  (s to change)",
+                            self.order.name()
+                        ));
+                    }
+                    Some(p) => p,
+                    None => return Some("".into()),
+                };
+                // Syllables are two columns wide
+                let n = (self.width / 2) as usize;
+                let mut line = fits
+                    .syllables()
+                    .take(n + 1)
+                    .map(char::from)
+                    .collect::<Vec<_>>();
+                if line.len() > n {
+                    line.truncate(n - 1);
+                    line.push('…');
+                }
+                return Some(line.into_iter().collect());
+            }
             _ => (),
         }
 
@@ -201,10 +751,9 @@ impl Block for Log {
             return None;
         }
 
-        if i == 0 && display_range.start != 0 {
-            Some('…'.into())
-        } else if i == display_range.end - 1
-            && display_range.end != self.line_count()
+        if (i == 0 && display_range.start != 0)
+            || (i == display_range.end - 1
+                && display_range.end != self.line_count())
         {
             Some('…'.into())
         } else {
@@ -212,27 +761,52 @@ impl Block for Log {
             let entry_index = real_line / Self::ENTRY_HEIGHT;
             let entry_line = real_line % Self::ENTRY_HEIGHT;
             match entry_line {
-                0 => {
-                    self.entries.get(entry_index).map(|(h, _)| format!("{}", h))
-                }
-                1 => self
-                    .entries
-                    .get(entry_index)
-                    .map(|(_, tl)| tl.rel_line(0))
+                0 => self.visible_entry(entry_index).map(|(k, e)| {
+                    let (h, _) = k;
+                    let word = format!("{}{}", h, self.sense_mark(k));
+                    match e.hanja.is_empty() {
+                        true => {
+                            format!("{} ({})", word, h.romanize(self.scheme))
+                        }
+                        false => format!(
+                            "{} {} ({})",
+                            word,
+                            e.hanja,
+                            h.romanize(self.scheme)
+                        ),
+                    }
+                }),
+                1 => self.visible_entry(entry_index).map(|((h, _), e)| {
+                    let mut line = format!("[{}]", h.pronounce());
+                    if let Some(pos) = e.details.part_of_speech {
+                        line.push_str(&format!("  {}", pos.name()));
+                    }
+                    if !e.seen.is_empty() {
+                        line.push_str(&format!("  seen as {}", e.seen));
+                    }
+                    line
+                }),
+                2 => self
+                    .visible_entry(entry_index)
+                    .map(|(_, e)| e.description.rel_line(0))
                     .unwrap_or(None),
-                2 => Some("".into()),
+                3 => Some("".into()),
                 _ => panic!("Logic Error!"),
             }
         }
     }
 
     fn style_line(&self, i: u16) -> Vec<(Range<usize>, ContentStyle)> {
-        let foc = if self.focused {
-            self.index
-        } else {
-            return vec![];
-        };
-        let i = if i < 2 {
+        let error = self.read_error.is_some() || self.save_error.is_some();
+        let i = if i == 0 && error {
+            return vec![(
+                0..usize::MAX,
+                ContentStyle {
+                    foreground_color: Some(Color::Red),
+                    ..Default::default()
+                },
+            )];
+        } else if i < 2 {
             return vec![];
         } else {
             (i as usize).saturating_sub(2)
@@ -240,15 +814,26 @@ impl Block for Log {
         let display_range = self.display_range();
         let real_line = display_range.start + i;
         let entry_index = real_line / Self::ENTRY_HEIGHT;
-
-        if entry_index != foc {
-            return vec![];
-        }
-
         let entry_line = real_line % Self::ENTRY_HEIGHT;
 
         match entry_line {
-            0 => vec![(
+            // Matches of the filter
+            2 => match self.rel_line(i as u16 + 2) {
+                Some(line) => match_ranges(&line, &self.filter)
+                    .into_iter()
+                    .map(|r| {
+                        (
+                            r,
+                            ContentStyle {
+                                foreground_color: Some(Color::Yellow),
+                                ..Default::default()
+                            },
+                        )
+                    })
+                    .collect(),
+                None => vec![],
+            },
+            0 if self.focused && entry_index == self.view_index() => vec![(
                 0..usize::MAX,
                 ContentStyle {
                     foreground_color: Some(Color::Black),
@@ -270,6 +855,15 @@ macro_rules! up {
         }
     };
 }
+macro_rules! enter {
+    () => {
+        KeyEvent {
+            code: KeyCode::Enter,
+            kind: KeyEventKind::Press,
+            ..
+        }
+    };
+}
 macro_rules! down {
     () => {
         KeyEvent {
@@ -279,17 +873,49 @@ macro_rules! down {
         }
     };
 }
+macro_rules! edit {
+    () => {
+        KeyEvent {
+            code: KeyCode::Char('e'),
+            kind: KeyEventKind::Press,
+            ..
+        }
+    };
+}
+macro_rules! sort {
+    () => {
+        KeyEvent {
+            code: KeyCode::Char('s'),
+            kind: KeyEventKind::Press,
+            ..
+        }
+    };
+}
 impl Input for Log {
     fn feed(&mut self, key: KeyEvent) -> TerminalCode {
         match key {
             up!() => {
-                self.index = self.index.saturating_sub(1);
+                let i = self.view_index().saturating_sub(1);
+                self.index = self.visible.get(i).copied().unwrap_or(0);
                 TerminalCode::None
             }
             down!() => {
-                self.index = (self.index + 1).min(self.entries.len() - 1);
+                let i = (self.view_index() + 1)
+                    .min(self.visible.len().saturating_sub(1));
+                self.index = self.visible.get(i).copied().unwrap_or(0);
+                TerminalCode::None
+            }
+            edit!() if !self.visible.is_empty() => {
+                TerminalCode::GoToScene("entry-details".into())
+            }
+            sort!() => {
+                self.order = self.order.next();
+                self.refresh_view();
                 TerminalCode::None
             }
+            enter!() if !self.visible.is_empty() => {
+                TerminalCode::GoToScene("conjugation".into())
+            }
             _ => TerminalCode::UnhandledKey(key),
         }
     }
@@ -310,3 +936,173 @@ impl Input for Log {
         self.focused = false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf, process};
+
+    use crate::{
+        backup,
+        elements::{Details, Log},
+        schema::PartOfSpeech,
+        text::Text,
+    };
+
+    /// An empty log in a directory of its own, named after the test
+    fn log(test: &str) -> (Log, PathBuf) {
+        let dir =
+            env::temp_dir().join(format!("langlog-{}-{}", test, process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let log = Log::new(dir.join("log.csv"), (0, 0, 0), 40, 10).unwrap();
+        (log, dir)
+    }
+
+    fn insert(log: &mut Log, hangul: &str, hanja: &str, description: &str) {
+        log.insert_entry(
+            Text::from(hangul),
+            hanja.into(),
+            "".into(),
+            description.into(),
+        );
+    }
+
+    fn visible(log: &Log) -> Vec<String> {
+        log.visible
+            .iter()
+            .map(|i| log.entries[*i].0.0.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_filter() {
+        let (mut log, dir) = log("filter");
+        insert(&mut log, "사랑", "", "love");
+        insert(&mut log, "연애", "", "사랑 romance");
+        insert(&mut log, "물", "", "water");
+        // Hangul is a pattern, and found in descriptions as well
+        log.set_filter("사랑");
+        assert_eq!(visible(&log), ["사랑", "연애"]);
+        log.set_filter("ㅁ*");
+        assert_eq!(visible(&log), ["물"]);
+        log.set_filter("wat");
+        assert_eq!(visible(&log), ["물"]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    fn senses(log: &Log, hangul: &str) -> Vec<(usize, String)> {
+        log.senses(&Text::from(hangul))
+            .into_iter()
+            .map(|((_, sense), description)| (sense, description))
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_sense() {
+        let (mut log, dir) = log("insert-sense");
+        insert(&mut log, "배", "", "boat");
+        insert(&mut log, "배", "", "pear");
+        insert(&mut log, "배", "", "boat");
+        assert_eq!(
+            senses(&log, "배"),
+            [(1, "boat".into()), (2, "pear".into())]
+        );
+        insert(&mut log, "은행", "銀行", "bank");
+        insert(&mut log, "은행", "銀杏", "ginkgo");
+        insert(&mut log, "은행", "銀行", "a bank");
+        insert(&mut log, "은행", "", "a bank");
+        assert_eq!(
+            senses(&log, "은행"),
+            [
+                (1, "a bank".into()),
+                (2, "ginkgo".into()),
+                (3, "a bank".into())
+            ]
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_remove_sense() {
+        let (mut log, dir) = log("remove-sense");
+        insert(&mut log, "배", "", "boat");
+        insert(&mut log, "배", "", "pear");
+        insert(&mut log, "배", "", "belly");
+        assert!(log.index_at_sense(&(Text::from("배"), 3)));
+        log.remove_entry(&(Text::from("배"), 1));
+        assert_eq!(
+            senses(&log, "배"),
+            [(1, "pear".into()), (2, "belly".into())]
+        );
+        assert_eq!(log.current_sense(), Some(&(Text::from("배"), 2)));
+        assert_eq!(log.current_description(), Some("belly"));
+
+        // Senses are read back in the order they were saved
+        let loaded = Log::new(log.path().into(), (0, 0, 0), 40, 10).unwrap();
+        assert_eq!(senses(&loaded, "배"), senses(&log, "배"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_keys_of() {
+        let (mut log, dir) = log("keys-of");
+        insert(&mut log, "바다", "", "sea");
+        insert(&mut log, "빨리", "", "quickly");
+        insert(&mut log, "배", "", "boat");
+        insert(&mut log, "배", "", "pear");
+        for (hangul, sense, part) in [
+            ("바다", 1, PartOfSpeech::Noun),
+            ("빨리", 1, PartOfSpeech::Adverb),
+            ("배", 1, PartOfSpeech::Noun),
+            ("배", 2, PartOfSpeech::Noun),
+        ] {
+            let details = Details {
+                part_of_speech: Some(part),
+                ..Default::default()
+            };
+            log.set_details(&(Text::from(hangul), sense), details);
+        }
+        let nouns = log.keys_of(&[PartOfSpeech::Noun]);
+        let nouns = nouns.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+        assert_eq!(nouns, ["바다", "배"]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_restore_unreadable() {
+        let (log, dir) = log("restore-unreadable");
+        let unreadable = "# langlog schema one\n";
+        fs::write(log.path(), unreadable).unwrap();
+        let mut log = Log::new(log.path().into(), (0, 0, 0), 40, 10).unwrap();
+        assert!(log.read_error.is_some());
+        let good = dir.join("good.csv");
+        fs::write(&good, "hangul;description\n집;House\n").unwrap();
+        log.restore(&good).unwrap();
+        assert!(log.read_error.is_none());
+        assert_eq!(senses(&log, "집"), [(1, "House".into())]);
+        // The unreadable file is kept as the newest backup
+        let newest = &backup::list(log.path())[0];
+        assert_eq!(fs::read_to_string(&newest.path).unwrap(), unreadable);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_unreadable() {
+        let dir = env::temp_dir()
+            .join(format!("langlog-unreadable-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("log.csv");
+        let content = "# langlog schema 99\nhangul;description\n집;House\n";
+        fs::write(&path, content).unwrap();
+        let mut log = Log::new(path.clone(), (0, 0, 0), 40, 10).unwrap();
+        assert!(log.read_error.is_some());
+        assert!(log.save_error.is_none());
+        // The change is kept in memory, but the user is told it is lost
+        insert(&mut log, "물", "", "water");
+        assert_eq!(senses(&log, "물"), [(1, "water".into())]);
+        assert!(log.save().is_err());
+        let error = log.save_error.clone().unwrap_or_default();
+        assert!(error.contains("not being saved"), "{}", error);
+        assert_eq!(fs::read_to_string(&path).unwrap(), content);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+use terminal::{
+    code::TerminalCode,
+    elements::{Dispatch, TextLine},
+    event::KeyEvent,
+    traits::{Block, Input},
+};
+
+use crate::elements::Log;
+
+/// ## Log Search
+/// Filters `log` by its descriptions while typing
+pub struct LogSearch {
+    input: TextLine,
+    log: Dispatch<Log>,
+}
+impl LogSearch {
+    pub fn new(input: TextLine, log: Dispatch<Log>) -> Self {
+        Self { input, log }
+    }
+
+    /// The log drops its filter to show an entry found elsewhere
+    fn is_stale(&self) -> bool {
+        self.log.read().unwrap().filter() != self.input.value()
+    }
+
+    fn sync(&mut self) {
+        if self.is_stale() {
+            self.input.clear();
+        }
+    }
+}
+impl Block for LogSearch {
+    fn pos(&self) -> (u16, u16, u16) {
+        self.input.pos()
+    }
+
+    fn rel_line(&self, i: u16) -> Option<String> {
+        match self.is_stale() {
+            true => (i == 0).then(String::new),
+            false => self.input.rel_line(i),
+        }
+    }
+}
+impl Input for LogSearch {
+    fn feed(&mut self, key: KeyEvent) -> TerminalCode {
+        self.sync();
+        let code = self.input.feed(key);
+        self.log.write().unwrap().set_filter(self.input.value());
+        code
+    }
+
+    fn rel_cursor_pos(&self) -> Option<(u16, u16)> {
+        self.input.rel_cursor_pos()
+    }
+
+    fn input_pos(&self) -> (u16, u16) {
+        self.input.input_pos()
+    }
+
+    fn focus(&mut self) {
+        self.sync()
+    }
+}
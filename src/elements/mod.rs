@@ -1,13 +1,35 @@
+mod backup_list;
+mod choice_list;
+mod conjugation_info;
+mod deck_list;
 mod description_input;
+mod entry_details;
+mod find_results;
 mod hangul_result;
 mod jamo_info;
+mod lemma_choice;
 mod log;
+mod log_search;
+mod mode_info;
+mod number_quiz;
+mod particle_drill;
 mod possible_info;
 mod rr_input;
 
+pub use backup_list::BackupList;
+pub use choice_list::ChoiceList;
+pub use conjugation_info::ConjugationInfo;
+pub use deck_list::DeckList;
 pub use description_input::DescriptionInput;
+pub use entry_details::EntryDetails;
+pub use find_results::FindResults;
 pub use hangul_result::HangulResult;
 pub use jamo_info::JamoInfo;
-pub use log::Log;
+pub use lemma_choice::LemmaChoice;
+pub use log::{Details, Log, Sense};
+pub use log_search::LogSearch;
+pub use mode_info::ModeInfo;
+pub use number_quiz::NumberQuiz;
+pub use particle_drill::ParticleDrill;
 pub use possible_info::PossibleInfo;
 pub use rr_input::RrInput;
@@ -0,0 +1,31 @@
+use terminal::{elements::Dispatch, traits::Block};
+
+use crate::elements::HangulResult;
+
+/// ## Mode Info
+/// Shows the active input mode of `hangul_result`, i.e. the
+/// romanization scheme or the keyboard layout
+pub struct ModeInfo {
+    pos: (u16, u16, u16),
+    hangul_result: Dispatch<HangulResult>,
+}
+impl ModeInfo {
+    pub fn new(
+        pos: (u16, u16, u16),
+        hangul_result: Dispatch<HangulResult>,
+    ) -> Self {
+        Self { pos, hangul_result }
+    }
+}
+impl Block for ModeInfo {
+    fn pos(&self) -> (u16, u16, u16) {
+        self.pos
+    }
+
+    fn rel_line(&self, i: u16) -> Option<String> {
+        match i {
+            0 => Some(self.hangul_result.read().unwrap().mode_name().into()),
+            _ => None,
+        }
+    }
+}
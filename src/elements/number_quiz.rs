@@ -0,0 +1,108 @@
+use terminal::traits::Block;
+
+use crate::{
+    ext::Xorshift,
+    numbers::{Counter, count, date, native, sino, time},
+    text::Text,
+};
+
+/// ## Number quiz
+/// Asks for numbers, counted things, dates and times to be written in
+/// Hangul
+#[derive(Debug, Clone)]
+pub struct NumberQuiz {
+    pos: (u16, u16, u16),
+    prompt: String,
+    answer: Text,
+    feedback: String,
+    correct: usize,
+    asked: usize,
+    rng: Xorshift,
+}
+impl NumberQuiz {
+    pub fn new(pos: (u16, u16, u16)) -> Self {
+        let mut ret = Self {
+            pos,
+            prompt: String::new(),
+            answer: Text::default(),
+            feedback: String::new(),
+            correct: 0,
+            asked: 0,
+            rng: Xorshift::from_time(),
+        };
+        ret.next_question();
+        ret
+    }
+
+    /// Grades `answer` and asks the next question
+    pub fn check(&mut self, answer: &Text) {
+        self.asked += 1;
+        // Spacing between the words is up to the learner
+        let unspaced = |t: &Text| {
+            t.to_string()
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+        };
+        if unspaced(answer) == unspaced(&self.answer) {
+            self.correct += 1;
+            self.feedback = "Correct!".into();
+        } else {
+            self.feedback = format!("Expected: {}", self.answer);
+        }
+        self.next_question();
+    }
+
+    fn next_question(&mut self) {
+        (self.prompt, self.answer) = match self.rng.below(5) {
+            0 => {
+                let digits = self.rng.below(5) as u32 + 1;
+                let n = self.rng.below(10u64.pow(digits));
+                (format!("Sino-Korean: {}", n), sino(n).into())
+            }
+            1 => {
+                let n = self.rng.below(99) + 1;
+                let native = native(n).unwrap_or_default();
+                (format!("Native: {}", n), native.into())
+            }
+            2 => {
+                let counters = Counter::all();
+                let counter =
+                    counters[self.rng.below(counters.len() as u64) as usize];
+                let n = match counter.is_native() {
+                    true => self.rng.below(30) + 1,
+                    false => self.rng.below(1000) + 1,
+                };
+                (format!("{} {}", n, counter.word()), count(n, counter))
+            }
+            3 => {
+                let (y, m, d) = (
+                    self.rng.below(100) + 1950,
+                    self.rng.below(12) + 1,
+                    self.rng.below(28) + 1,
+                );
+                let answer = date(y, m, d).unwrap_or_default();
+                (format!("{}년 {}월 {}일", y, m, d), answer)
+            }
+            _ => {
+                let (h, m) = (self.rng.below(12) + 1, self.rng.below(12) * 5);
+                let answer = time(h, m).unwrap_or_default();
+                (format!("{}:{:02}", h, m), answer)
+            }
+        };
+    }
+}
+impl Block for NumberQuiz {
+    fn pos(&self) -> (u16, u16, u16) {
+        self.pos
+    }
+
+    fn rel_line(&self, i: u16) -> Option<String> {
+        match i {
+            0 => Some(format!("Write in Hangul:  {}", self.prompt)),
+            1 => Some(self.feedback.clone()),
+            2 => Some(format!("Score: {}/{}", self.correct, self.asked)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use terminal::{elements::Dispatch, traits::Block};
+
+use crate::{
+    elements::Log,
+    ext::Xorshift,
+    hangul::Hangul,
+    particles::Particle,
+    schema::PartOfSpeech,
+    text::{Segment, Text},
+};
+
+/// Parts of speech particles attach to
+const NOUNS: [PartOfSpeech; 3] = [
+    PartOfSpeech::Noun,
+    PartOfSpeech::Pronoun,
+    PartOfSpeech::Numeral,
+];
+
+/// ## Particle drill
+/// Asks for a noun of `log` to be written with a particle attached, out
+/// of the entries marked as nouns, pronouns or numerals
+pub struct ParticleDrill {
+    pos: (u16, u16, u16),
+    log: Dispatch<Log>,
+    question: Option<(Hangul, Particle)>,
+    feedback: String,
+    correct: usize,
+    asked: usize,
+    rng: Xorshift,
+}
+impl ParticleDrill {
+    pub fn new(pos: (u16, u16, u16), log: Dispatch<Log>) -> Self {
+        let mut ret = Self {
+            pos,
+            log,
+            question: None,
+            feedback: String::new(),
+            correct: 0,
+            asked: 0,
+            rng: Xorshift::from_time(),
+        };
+        ret.next_question();
+        ret
+    }
+
+    /// Grades `answer` and asks the next question
+    pub fn check(&mut self, answer: &Text) {
+        if let Some((noun, particle)) = &self.question {
+            let expected = particle.attach(noun);
+            self.asked += 1;
+            // Spacing is up to the learner
+            let unspaced = |s: String| {
+                s.chars().filter(|c| !c.is_whitespace()).collect::<String>()
+            };
+            if unspaced(answer.to_string()) == unspaced(expected.to_string()) {
+                self.correct += 1;
+                self.feedback = "Correct!".into();
+            } else {
+                self.feedback = format!("Expected: {}", expected);
+            }
+        }
+        self.next_question();
+    }
+
+    fn next_question(&mut self) {
+        let nouns = self
+            .log
+            .read()
+            .unwrap()
+            .keys_of(&NOUNS)
+            .into_iter()
+            .filter_map(|t| match t.as_slice() {
+                [Segment::Hangul(h)] => Some(h.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if nouns.is_empty() {
+            self.question = None;
+            return;
+        }
+        let particles = Particle::all();
+        let noun = &nouns[self.rng.below(nouns.len() as u64) as usize];
+        let particle =
+            particles[self.rng.below(particles.len() as u64) as usize];
+        self.question = Some((noun.clone(), particle));
+    }
+}
+impl Block for ParticleDrill {
+    fn pos(&self) -> (u16, u16, u16) {
+        self.pos
+    }
+
+    fn rel_line(&self, i: u16) -> Option<String> {
+        match i {
+            0 => Some(match &self.question {
+                Some((noun, particle)) => {
+                    format!("Attach {} to:  {}", particle.name(), noun)
+                }
+                None => "No entries of the log are marked as nouns".into(),
+            }),
+            1 => Some(self.feedback.clone()),
+            2 => Some(format!("Score: {}/{}", self.correct, self.asked)),
+            _ => None,
+        }
+    }
+}
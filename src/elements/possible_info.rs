@@ -13,6 +13,25 @@ impl PossibleInfo {
     ) -> Self {
         Self { pos, hangul_result }
     }
+
+    /// Lists the readings of an ambiguous word, the chosen one in
+    /// brackets
+    fn title(&self) -> String {
+        let hangul_result = self.hangul_result.read().unwrap();
+        let readings = hangul_result.readings();
+        if readings.len() < 2 {
+            return "Combinations:".into();
+        }
+        let chosen = hangul_result.reading();
+        let readings = readings
+            .iter()
+            .map(|h| match Some(h) == chosen {
+                true => format!("[{}]", h),
+                false => h.to_string(),
+            })
+            .collect::<Vec<_>>();
+        format!("Readings: {}", readings.join(" "))
+    }
 }
 impl Block for PossibleInfo {
     fn pos(&self) -> (u16, u16, u16) {
@@ -21,7 +40,7 @@ impl Block for PossibleInfo {
 
     fn rel_line(&self, i: u16) -> Option<String> {
         match i {
-            0 => Some("Combinations:".into()),
+            0 => Some(self.title()),
             1 => {
                 let mut str: String = "".into();
                 if let Some(possible) =
@@ -45,19 +64,26 @@ impl Block for PossibleInfo {
                 }
             }
             2 => {
+                let scheme = self.hangul_result.read().unwrap().scheme();
                 let mut str: String = "".into();
                 if let Some(possible) =
                     self.hangul_result.read().unwrap().syllable().finale()
                 {
                     for j in possible.append_possible() {
-                        str.push_str(&format!("{:<4}", Jamo::from(j).rr()));
+                        str.push_str(&format!(
+                            "{:<4}",
+                            scheme.spelling(j.into())
+                        ));
                     }
                     Some(str)
                 } else if let Some(possible) =
                     self.hangul_result.read().unwrap().syllable().medial()
                 {
                     for j in possible.combine_possible() {
-                        str.push_str(&format!("{:<4}", Jamo::from(j).rr()));
+                        str.push_str(&format!(
+                            "{:<4}",
+                            scheme.spelling(j.into())
+                        ));
                     }
                     Some(str)
                 } else {
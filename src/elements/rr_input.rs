@@ -20,6 +20,44 @@ macro_rules! enter {
         }
     };
 }
+macro_rules! space {
+    () => {
+        KeyEvent {
+            code: KeyCode::Char(' '),
+            kind: KeyEventKind::Press,
+            modifiers: KeyModifiers::NONE,
+            ..
+        }
+    };
+}
+macro_rules! ctrl {
+    ($c:expr) => {
+        KeyEvent {
+            code: KeyCode::Char($c),
+            kind: KeyEventKind::Press,
+            modifiers: KeyModifiers::CONTROL,
+            ..
+        }
+    };
+}
+macro_rules! up {
+    () => {
+        KeyEvent {
+            code: KeyCode::Up,
+            kind: KeyEventKind::Press,
+            ..
+        }
+    };
+}
+macro_rules! down {
+    () => {
+        KeyEvent {
+            code: KeyCode::Down,
+            kind: KeyEventKind::Press,
+            ..
+        }
+    };
+}
 macro_rules! back_space {
     () => {
         KeyEvent {
@@ -52,6 +90,56 @@ impl RrInput {
     pub fn hangul(&self) -> Dispatch<HangulResult> {
         self.hangul_result.clone()
     }
+
+    /// Switches between romanization and keyboard layouts
+    fn next_mode(&mut self) {
+        self.input.clear();
+        self.hangul_result.write().unwrap().next_mode();
+    }
+
+    /// Key presses are mapped to jamo by the active layout, other
+    /// characters are submitted verbatim
+    fn feed_keyboard(&mut self, key: KeyEvent) -> TerminalCode {
+        let mut hangul = self.hangul_result.write().unwrap();
+        match key {
+            space!() => {
+                hangul.push();
+                hangul.push_space();
+            }
+            enter!() => hangul.push(),
+            back_space!()
+                if hangul.is_empty() && hangul.syllable().is_empty() =>
+            {
+                return TerminalCode::UnhandledKey(key);
+            }
+            back_space!() => hangul.pop_key(),
+            KeyEvent {
+                code: KeyCode::Char(c),
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+                ..
+            } => {
+                if !hangul.push_key(c) {
+                    hangul.push();
+                    hangul.push_literal(&c.to_string());
+                }
+            }
+            _ => return TerminalCode::UnhandledKey(key),
+        }
+        TerminalCode::None
+    }
+
+    /// Submits the pending word or literal, the overflow is kept as
+    /// input
+    fn submit(&mut self) {
+        self.hangul_result.write().unwrap().push();
+        self.input
+            .with_value(self.hangul_result.read().unwrap().overflow().clone());
+        self.hangul_result
+            .write()
+            .unwrap()
+            .set_rr(self.input.value());
+    }
 }
 impl Block for RrInput {
     fn pos(&self) -> (u16, u16, u16) {
@@ -80,8 +168,7 @@ impl Block for RrInput {
         }
         let mut error_range =
             0..self.hangul_result.read().unwrap().overflow().len();
-        let diff = (self.input.char_count() as usize)
-            .saturating_sub(error_range.len());
+        let diff = self.input.char_count().saturating_sub(error_range.len());
         error_range.start += diff;
         error_range.end += diff;
 
@@ -93,6 +180,23 @@ impl Input for RrInput {
         &mut self,
         key: terminal::event::KeyEvent,
     ) -> terminal::code::TerminalCode {
+        if matches!(key, ctrl!('k')) {
+            self.next_mode();
+            return TerminalCode::None;
+        }
+        if self.hangul_result.read().unwrap().layout().is_some() {
+            return self.feed_keyboard(key);
+        }
+        // Spaces separate words unless typed as part of a literal
+        if matches!(key, space!())
+            && !self.input.value().starts_with(HangulResult::LITERAL)
+        {
+            self.submit();
+            if self.input.value().is_empty() {
+                self.hangul_result.write().unwrap().push_space();
+            }
+            return TerminalCode::None;
+        }
         match self.input.feed(key) {
             TerminalCode::None => {
                 self.hangul_result
@@ -102,14 +206,16 @@ impl Input for RrInput {
                 TerminalCode::None
             }
             TerminalCode::UnhandledKey(enter!()) => {
-                self.hangul_result.write().unwrap().push();
-                self.input.with_value(
-                    self.hangul_result.read().unwrap().overflow().clone(),
-                );
-                self.hangul_result
-                    .write()
-                    .unwrap()
-                    .set_rr(self.input.value());
+                self.submit();
+                TerminalCode::None
+            }
+            // Choose between the readings of an ambiguous word
+            TerminalCode::UnhandledKey(up!()) => {
+                self.hangul_result.write().unwrap().previous_reading();
+                TerminalCode::None
+            }
+            TerminalCode::UnhandledKey(down!()) => {
+                self.hangul_result.write().unwrap().next_reading();
                 TerminalCode::None
             }
             c @ TerminalCode::UnhandledKey(back_space!()) => {
@@ -131,4 +237,13 @@ impl Input for RrInput {
     fn input_pos(&self) -> (u16, u16) {
         self.input.input_pos()
     }
+
+    /// The syllable being typed with a keyboard layout is submitted when
+    /// leaving the input, like an IME would
+    fn unfocus(&mut self) {
+        let mut hangul = self.hangul_result.write().unwrap();
+        if hangul.layout().is_some() {
+            hangul.push();
+        }
+    }
 }
@@ -1,8 +1,75 @@
 #![allow(dead_code)]
 
-use std::{collections::BTreeMap, ops::Deref};
+use std::{
+    collections::BTreeMap,
+    ops::Deref,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-#[derive(Debug)]
+/// ## Xorshift
+/// Small pseudo random generator for drills, seeded from the clock
+#[derive(Debug, Clone)]
+pub struct Xorshift(u64);
+impl Xorshift {
+    pub fn from_time() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(1);
+        Self(seed | 1)
+    }
+
+    /// Below `bound`
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % bound.max(1)
+    }
+}
+
+/// Seconds since the Unix epoch
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// `YYYY-MM-DD` of `secs` since the Unix epoch, in UTC
+pub fn date(secs: u64) -> String {
+    // Days to civil date, after Howard Hinnant
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// `YYYY-MM-DD HH:MM` of `secs` since the Unix epoch, in UTC
+pub fn date_time(secs: u64) -> String {
+    format!(
+        "{} {:02}:{:02}",
+        date(secs),
+        secs / 3600 % 24,
+        secs / 60 % 60
+    )
+}
+
+/// The only character of `s`
+pub fn single_char(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Tree<K, V> {
     path: Vec<K>,
     val: Option<V>,
@@ -38,9 +105,9 @@ where
                 .insert(k.clone(), Box::new(Self::with_path(prefix)));
         }
 
-        self.connections
-            .get_mut(k)
-            .map(|node| node.insert(key, value));
+        if let Some(node) = self.connections.get_mut(k) {
+            node.insert(key, value);
+        }
     }
 
     pub fn get<'a>(
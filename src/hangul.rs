@@ -6,8 +6,10 @@ use std::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    jamo::Jamo,
-    syllable::{Syllable, SyllableError},
+    jamo::{FinalJamo, InitialJamo, Jamo, MedialJamo},
+    pronunciation::pronounce,
+    romanization::Scheme,
+    syllable::{State, Syllable, SyllableError},
 };
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -22,11 +24,9 @@ impl Hangul {
             }
         };
 
-        let overflow = syl.push(jamo)?;
-        match overflow {
-            Some(new_syl) => self.push(new_syl),
-            None => (),
-        };
+        if let Some(new_syl) = syl.push(jamo)? {
+            self.push(new_syl);
+        }
 
         Ok(())
     }
@@ -47,6 +47,76 @@ impl Hangul {
         }
     }
 
+    /// Appends `c` which is either a precomposed syllable, a compatibility
+    /// jamo or a conjoining jamo, so that both NFC and NFD text can be read
+    pub fn push_char(&mut self, c: char) -> HangulResult<()> {
+        if let Ok(ij) = InitialJamo::try_from(c) {
+            self.push(ij.into());
+        } else if let Ok(mj) = MedialJamo::try_from(c) {
+            match self.last_mut() {
+                Some(syl) if syl.state() == State::Medial => {
+                    syl.push(mj.into())?;
+                }
+                _ => self.push(mj.into()),
+            }
+        } else if let Ok(fj) = FinalJamo::try_from(c) {
+            match self.last_mut() {
+                Some(syl)
+                    if syl.medial().is_some() && syl.finale().is_none() =>
+                {
+                    syl.set_finale(Some(fj))
+                }
+                _ => return Err(HangulError::UnexpectedFinal(c)),
+            }
+        } else if let Ok(j) = Jamo::try_from(c) {
+            self.push(j.try_into()?);
+        } else {
+            self.push(c.try_into()?);
+        }
+        Ok(())
+    }
+
+    /// Composed (NFC) form, one precomposed character per syllable
+    pub fn nfc(&self) -> String {
+        String::from(self)
+    }
+
+    /// Decomposed (NFD) form, see `Syllable::nfd`
+    pub fn nfd(&self) -> String {
+        self.iter().map(Syllable::nfd).collect()
+    }
+
+    /// Spoken form, see `pronunciation::pronounce`
+    pub fn pronounce(&self) -> Hangul {
+        pronounce(self)
+    }
+
+    /// Romanization of the pronounced word
+    pub fn romanize(&self, scheme: Scheme) -> String {
+        scheme.romanize(self)
+    }
+
+    /// Whether the last syllable has a final consonant (받침)
+    pub fn ends_in_consonant(&self) -> bool {
+        self.last().is_some_and(|s| s.finale().is_some())
+    }
+
+    /// Whether every syllable is a bare initial, e.g. `ㅎㄱ`
+    pub fn is_initials(&self) -> bool {
+        !self.is_empty() && self.iter().all(|s| s.medial().is_none())
+    }
+
+    /// Initial of every syllable (초성), e.g. `ㅎㄱ` for `한국`
+    pub fn initials(&self) -> Vec<InitialJamo> {
+        self.iter().filter_map(Syllable::initial).collect()
+    }
+
+    /// Whether the last syllable ends in `ㄹ`
+    pub fn ends_in_r(&self) -> bool {
+        self.last()
+            .is_some_and(|s| s.finale() == Some(FinalJamo::R))
+    }
+
     #[allow(dead_code)]
     pub fn break_with(&mut self, jamo: Jamo) -> HangulResult<()> {
         self.push(jamo.try_into()?);
@@ -85,7 +155,7 @@ impl From<Syllable> for Hangul {
 }
 impl From<&Syllable> for Hangul {
     fn from(value: &Syllable) -> Self {
-        Self(vec![value.clone()])
+        Self(vec![*value])
     }
 }
 impl TryFrom<Jamo> for Hangul {
@@ -117,10 +187,11 @@ impl<'a> TryFrom<&'a str> for Hangul {
     type Error = HangulError;
 
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        value
-            .chars()
-            .map(|c| Ok(Syllable::try_from(c)?))
-            .collect::<HangulResult<Hangul>>()
+        let mut hangul = Self::default();
+        for c in value.chars() {
+            hangul.push_char(c)?;
+        }
+        Ok(hangul)
     }
 }
 impl From<Hangul> for String {
@@ -160,7 +231,41 @@ impl<'de> Deserialize<'de> for Hangul {
 
 #[derive(Debug, thiserror::Error)]
 pub enum HangulError {
+    #[error("Final jamo {0} does not follow a medial")]
+    UnexpectedFinal(char),
     #[error(transparent)]
     Syllable(#[from] SyllableError),
 }
 pub type HangulResult<T> = Result<T, HangulError>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{hangul::Hangul, syllable::Syllable};
+
+    #[test]
+    fn test_normalization() {
+        for c in '가'..='힣' {
+            let hangul = Hangul::from(Syllable::try_from(c).unwrap());
+            assert_eq!(
+                Hangul::try_from(hangul.nfd().as_str()).unwrap(),
+                hangul
+            );
+        }
+        let nfd = "\u{1112}\u{1161}\u{11ab}\u{1100}\u{1173}\u{11af}";
+        let hangul = Hangul::try_from(nfd).unwrap();
+        assert_eq!(hangul.nfc(), "한글");
+        assert_eq!(hangul.nfd(), nfd);
+        // Precomposed syllable followed by a conjoining final
+        assert_eq!(Hangul::try_from("가\u{11a8}").unwrap().nfc(), "각");
+        assert!(Hangul::try_from("\u{11a8}").is_err());
+    }
+
+    #[test]
+    fn test_initials() {
+        let initials = Hangul::try_from("ㅎㄱ").unwrap();
+        assert!(initials.is_initials());
+        assert!(!Hangul::try_from("한ㄱ").unwrap().is_initials());
+        let hanguk = Hangul::try_from("한국").unwrap();
+        assert_eq!(hanguk.initials(), initials.initials());
+    }
+}
@@ -0,0 +1,354 @@
+mod spelling;
+
+pub use spelling::{SpellingResult, Spellings};
+
+use spelling::MAX_TOKEN;
+
+use crate::{
+    hangul::Hangul,
+    jamo::{InitialJamo, Jamo, MedialJamo},
+    romanization::Scheme,
+    syllable::Syllable,
+};
+
+pub type IsBreak = bool;
+/// Syllable count and count of syllables starting with a vowel, lower
+/// is preferred
+type Cost = (usize, usize);
+
+/// Most readings kept by `HangulParser::segment`
+const READINGS: usize = 8;
+
+fn is_break(c: char) -> bool {
+    matches!(c, ' ' | '-' | '\'' | '.')
+}
+
+#[derive(Debug, Clone)]
+pub struct HangulParser {
+    scheme: Scheme,
+    spellings: Spellings,
+}
+impl HangulParser {
+    /// Reads the shipped spellings of `scheme`
+    pub fn new(scheme: Scheme) -> Self {
+        Self {
+            scheme,
+            spellings: Spellings::builtin(scheme),
+        }
+    }
+
+    /// Reads the spellings of `scheme` including house conventions, see
+    /// `Spellings::load`
+    pub fn load(scheme: Scheme) -> SpellingResult<Self> {
+        Ok(Self {
+            scheme,
+            spellings: Spellings::load(scheme)?,
+        })
+    }
+
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    pub fn parse_jamo<'a>(
+        &self,
+        input: &'a str,
+        syllable: &Syllable,
+    ) -> (Option<Jamo>, IsBreak, &'a str) {
+        let mut input = input;
+        let mut broken = false;
+        while input.chars().next().is_none_or(is_break) {
+            broken = true;
+            if input.is_empty() {
+                return (None, broken, input);
+            }
+            input = input.split_at(1).1;
+        }
+        for len in (1..=MAX_TOKEN).rev() {
+            let end = input
+                .char_indices()
+                .nth(len)
+                .map(|(i, _)| i)
+                .unwrap_or(input.len());
+            let (s, ret) = input.split_at(end);
+
+            if let Some(j) = self.spellings.get(s, syllable).first() {
+                return (Some(*j), broken, ret);
+            }
+        }
+        (None, broken, input)
+    }
+
+    pub fn parse_syllable<'a>(&self, input: &'a str) -> (Syllable, &'a str) {
+        let mut i = input;
+        let mut syl = Syllable::default();
+        while !i.is_empty() {
+            let (jamo, pi) = match self.parse_jamo(i, &syl) {
+                // A break ends the syllable
+                (Some(_), true, _) if !syl.is_empty() => return (syl, i),
+                (Some(j), _, rst) => (j, rst),
+                (None, _, ret) => return (syl, ret),
+            };
+
+            match syl.push(jamo) {
+                Ok(None) => (),
+                _ => return (syl, i),
+            }
+
+            i = pi;
+        }
+        (syl, i)
+    }
+
+    /// Parses all of `input` one syllable at a time, stops at the
+    /// first part which can not be parsed
+    pub fn parse(&self, input: &str) -> Hangul {
+        let mut ret = Hangul::default();
+        let mut i = input;
+        while !i.is_empty() {
+            let (syl, rest) = self.parse_syllable(i);
+            if syl.is_empty() || rest.len() == i.len() {
+                break;
+            }
+            ret.push(syl);
+            i = rest;
+        }
+        ret
+    }
+
+    /// ## Segment
+    /// Reads a whole word, choosing the syllable boundaries with the
+    /// `Syllable` state machine. Breaks always end a syllable.
+    ///
+    /// Returns the readings of the longest part of `input` which can be
+    /// read, best first, and the rest of `input`. Fewer syllables, then
+    /// fewer syllables starting with a vowel, are preferred, e.g.
+    /// "hangang" reads `한강` before `항앙`.
+    pub fn segment<'a>(&self, input: &'a str) -> (Vec<Hangul>, &'a str) {
+        let readings = self.readings(input);
+        if !readings.is_empty() {
+            return (readings, "");
+        }
+        // Consonants alone are initials, e.g. "hg" reads `ㅎㄱ`
+        if let Some(initials) = self.initials(input) {
+            return (vec![initials], "");
+        }
+        let ends = input.char_indices().map(|(i, _)| i).rev();
+        for end in ends {
+            let readings = self.readings(&input[..end]);
+            if !readings.is_empty() {
+                return (readings, &input[end..]);
+            }
+        }
+        (vec![], input)
+    }
+
+    /// Reads all of `input` as bare initials, longer tokens first. `ㅇ`
+    /// may be spelled as its final, e.g. "ng" in RR.
+    fn initials(&self, input: &str) -> Option<Hangul> {
+        let start = Syllable::default();
+        // Bare `ㅇ` is in final position
+        let after_vowel = Syllable::from(MedialJamo::A);
+        // Initials of `input[i..]`, built from the end
+        let mut best: Vec<Option<Hangul>> = vec![None; input.len() + 1];
+        best[input.len()] = Some(Hangul::default());
+        for (i, _) in input.char_indices().rev() {
+            let ng = self
+                .prefixes(&input[i..], &after_vowel)
+                .into_iter()
+                .filter(|(jamo, _)| *jamo == Jamo::Ng);
+            let mut prefixes = self.prefixes(&input[i..], &start);
+            prefixes.extend(ng);
+            prefixes.sort_by_key(|(_, len)| usize::MAX - len);
+            best[i] = prefixes.into_iter().find_map(|(jamo, len)| {
+                let initial = InitialJamo::try_from(jamo).ok()?;
+                let mut rest = best[i + len].clone()?;
+                rest.insert(0, initial.into());
+                Some(rest)
+            });
+        }
+        best.swap_remove(0)
+    }
+
+    /// Best readings of all of `input`
+    fn readings(&self, input: &str) -> Vec<Hangul> {
+        // Readings of `input[i..]`, built from the end
+        let mut best: Vec<Vec<(Cost, Vec<Syllable>)>> =
+            vec![vec![]; input.len() + 1];
+        best[input.len()] = vec![((0, 0), vec![])];
+        for (start, c) in input.char_indices().rev() {
+            if is_break(c) {
+                best[start] = best[start + c.len_utf8()].clone();
+                continue;
+            }
+            let mut readings = vec![];
+            for (syllable, end, cost) in self.syllables(input, start) {
+                for ((n, v), rest) in &best[end] {
+                    let mut syllables = vec![syllable];
+                    syllables.extend(rest);
+                    readings.push(((cost.0 + n, cost.1 + v), syllables));
+                }
+            }
+            readings.sort_by_key(|(cost, _)| *cost);
+            for reading in readings {
+                if best[start].len() == READINGS {
+                    break;
+                }
+                if !best[start].iter().any(|(_, s)| *s == reading.1) {
+                    best[start].push(reading);
+                }
+            }
+        }
+        best.swap_remove(0)
+            .into_iter()
+            .map(|(_, syllables)| syllables.into_iter().collect())
+            .collect()
+    }
+
+    /// Every syllable at the start of `input[start..]` with the position
+    /// it ends at. Only the end of `input` may end a syllable without a
+    /// vowel.
+    fn syllables(
+        &self,
+        input: &str,
+        start: usize,
+    ) -> Vec<(Syllable, usize, Cost)> {
+        let mut ret: Vec<(Syllable, usize, Cost)> = vec![];
+        let mut stack = vec![(Syllable::default(), start, (1, 0))];
+        while let Some((syllable, pos, cost)) = stack.pop() {
+            for (jamo, len) in self.prefixes(&input[pos..], &syllable) {
+                let mut next = syllable;
+                if !matches!(next.push(jamo), Ok(None)) {
+                    continue;
+                }
+                // Compound vowels have a spelling of their own
+                if syllable.medial().is_some()
+                    && next.medial() != syllable.medial()
+                {
+                    continue;
+                }
+                let cost = match MedialJamo::try_from(jamo) {
+                    Ok(_) if syllable.is_empty() => (1, 1),
+                    _ => cost,
+                };
+                let end = pos + len;
+                if (next.medial().is_some() || end == input.len())
+                    && !ret.iter().any(|(s, e, _)| *s == next && *e == end)
+                {
+                    ret.push((next, end, cost));
+                }
+                stack.push((next, end, cost));
+            }
+        }
+        ret
+    }
+
+    /// Jamo spelled by the first characters of `input` when added to
+    /// `syllable`
+    fn prefixes(&self, input: &str, syllable: &Syllable) -> Vec<(Jamo, usize)> {
+        input
+            .char_indices()
+            .map(|(i, c)| i + c.len_utf8())
+            .take(MAX_TOKEN)
+            .flat_map(|end| {
+                self.spellings
+                    .get(&input[..end], syllable)
+                    .into_iter()
+                    .map(move |j| (j, end))
+            })
+            .collect()
+    }
+}
+impl Default for HangulParser {
+    fn default() -> Self {
+        Self::new(Scheme::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        hangul_parser::{HangulParser, Spellings},
+        jamo::Jamo,
+        romanization::Scheme,
+        syllable::Syllable,
+    };
+
+    fn readings(scheme: Scheme, input: &str) -> Vec<String> {
+        let (readings, rest) = HangulParser::new(scheme).segment(input);
+        assert_eq!(rest, "", "{}", input);
+        readings.iter().map(|h| h.to_string()).collect()
+    }
+
+    #[test]
+    fn test_segment() {
+        assert_eq!(readings(Scheme::Rr, "annyeonghaseyo")[0], "안녕하세요");
+        assert_eq!(readings(Scheme::Rr, "hae")[0], "해");
+        let hangang = readings(Scheme::Rr, "hangang");
+        assert_eq!(hangang[0], "한강");
+        assert!(hangang.contains(&"항앙".to_string()));
+        assert_eq!(readings(Scheme::Rr, "hang-ang")[0], "항앙");
+        assert_eq!(readings(Scheme::Rr, "boa")[0], "보아");
+        assert_eq!(readings(Scheme::Mr, "han'guk")[0], "한국");
+        assert_eq!(readings(Scheme::Yale, "hankwuke")[0], "한구거");
+    }
+
+    #[test]
+    fn test_aliases() {
+        let parser = HangulParser::new(Scheme::Rr);
+        let parse = |s: &str| parser.parse(s).to_string();
+        assert_eq!(parse("mul"), parse("mur"));
+        assert_eq!(parse("dak"), parse("dag"));
+        assert_eq!(parse("bap"), parse("bab"));
+        assert_eq!(parse("dalg"), "닭");
+        assert_eq!(parse("kkot"), "꼳");
+        // Finals read as they sound may be the aspirated consonant
+        let kkot = readings(Scheme::Rr, "kkot");
+        assert!(kkot.contains(&"꼳".to_string()), "{:?}", kkot);
+        assert!(kkot.contains(&"꽃".to_string()), "{:?}", kkot);
+        for (input, word) in [
+            ("bat", "밭"),
+            ("ap", "앞"),
+            ("bueok", "부엌"),
+            ("gat", "같"),
+        ] {
+            assert!(readings(Scheme::Rr, input).contains(&word.into()));
+        }
+        assert_eq!(parse("ppang"), "빵");
+        assert_eq!(readings(Scheme::Rr, "hangeul")[0], "한글");
+        assert_eq!(readings(Scheme::Rr, "ollae")[0], "올래");
+    }
+
+    #[test]
+    fn test_house_conventions() {
+        let start = Syllable::default();
+        let mut spellings = Spellings::builtin(Scheme::Rr);
+        let house = "token;position;jamo\noo;any;ㅜ\nx;any;ㅅ\n";
+        spellings.extend("house", house.as_bytes()).unwrap();
+        assert_eq!(spellings.get("x", &start), [Jamo::S]);
+        assert_eq!(spellings.get("oo", &start), [Jamo::U]);
+        for invalid in ["k;any;ㄱ", "abcd;any;ㅏ", "-a;any;ㅏ", "a;final;ㅏ"]
+        {
+            let definition = format!("token;position;jamo\n{}\n", invalid);
+            let mut spellings = Spellings::builtin(Scheme::Rr);
+            assert!(
+                spellings.extend("house", definition.as_bytes()).is_err(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_segment_rest() {
+        let parser = HangulParser::new(Scheme::Rr);
+        let (readings, rest) = parser.segment("hanx");
+        assert_eq!(readings[0].to_string(), "한");
+        assert_eq!(rest, "x");
+        assert_eq!(parser.segment("hadd").0[0].to_string(), "하ㄸ");
+        assert_eq!(parser.segment("hg").0[0].to_string(), "ㅎㄱ");
+        assert_eq!(parser.segment("kkch").0[0].to_string(), "ㄲㅊ");
+        assert_eq!(parser.segment("hng").0[0].to_string(), "ㅎㅇ");
+        assert_eq!(parser.segment("ngg").0[0].to_string(), "ㅇㄱ");
+    }
+}
@@ -0,0 +1,214 @@
+use std::{collections::HashMap, env, fmt, fs, io};
+
+use csv::ReaderBuilder;
+use serde::Deserialize;
+
+use crate::{
+    ext::Tree,
+    jamo::{FinalJamo, InitialJamo, Jamo},
+    romanization::Scheme,
+    syllable::{State, Syllable},
+};
+
+const RR: &str = include_str!("schemes/rr.csv");
+const MR: &str = include_str!("schemes/mr.csv");
+const YALE: &str = include_str!("schemes/yale.csv");
+
+/// Most characters in a token
+pub const MAX_TOKEN: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Position {
+    Any,
+    Initial,
+    Final,
+}
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Position::Any => "any",
+            Position::Initial => "initial",
+            Position::Final => "final",
+        })
+    }
+}
+
+/// One line of a scheme file
+#[derive(Debug, Deserialize)]
+struct Row {
+    token: String,
+    position: Position,
+    jamo: String,
+}
+
+/// ## Spellings
+/// Tokens read as jamo by a romanization scheme.
+///
+/// Schemes are defined in `;` separated files with the header
+/// `token;position;jamo`, where position is one of `any`, `initial` or
+/// `final` and jamo is a compatibility jamo, e.g. `ㄱ`, or several to
+/// try in that order. Tokens with a position are only read there and are
+/// tried before `any`, e.g. a final `k` is read as `ㄱ`, then as `ㅋ`.
+/// Lines starting with `#` are comments.
+#[derive(Debug, Clone, Default)]
+pub struct Spellings {
+    any: Tree<char, Vec<Jamo>>,
+    initial: Tree<char, Vec<Jamo>>,
+    finale: Tree<char, Vec<Jamo>>,
+    /// Every token read so far, to find conflicts
+    tokens: HashMap<(String, Position), Vec<Jamo>>,
+}
+impl Spellings {
+    /// Shipped spellings of `scheme`
+    pub fn builtin(scheme: Scheme) -> Spellings {
+        let definition = match scheme {
+            Scheme::Rr => RR,
+            Scheme::Mr => MR,
+            Scheme::Yale => YALE,
+        };
+        let mut ret = Self::default();
+        ret.extend(scheme.name(), definition.as_bytes())
+            .expect("Invalid builtin scheme");
+        ret
+    }
+
+    /// Shipped spellings of `scheme` with the house conventions in
+    /// `$CWD/schemes`, e.g. `schemes/rr.csv`
+    pub fn load(scheme: Scheme) -> SpellingResult<Spellings> {
+        let mut ret = Self::builtin(scheme);
+        let dir = env::current_dir()?.join("schemes");
+        if !dir.is_dir() {
+            return Ok(ret);
+        }
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|e| e != "csv") {
+                continue;
+            }
+            let name = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            match name.parse::<Scheme>() {
+                Ok(s) if s == scheme => {
+                    ret.extend(&name, fs::File::open(&path)?)?
+                }
+                Ok(_) => (),
+                Err(_) => return Err(SpellingError::UnknownScheme(name)),
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Adds the tokens of a scheme definition, a token may not be read
+    /// as different jamo on two lines of the same position
+    pub fn extend(
+        &mut self,
+        name: &str,
+        definition: impl io::Read,
+    ) -> SpellingResult<()> {
+        let mut rdr = ReaderBuilder::new()
+            .delimiter(b';')
+            .comment(Some(b'#'))
+            .from_reader(definition);
+        for record in rdr.records() {
+            let record = record?;
+            let line = record.position().map(|p| p.line()).unwrap_or(0);
+            let row: Row = record.deserialize(None)?;
+            if !reachable(&row.token) {
+                return Err(SpellingError::Unreachable(
+                    name.into(),
+                    line,
+                    row.token,
+                ));
+            }
+            let jamo = Self::jamo(&row).ok_or_else(|| {
+                SpellingError::InvalidJamo(
+                    name.into(),
+                    line,
+                    row.jamo.clone(),
+                    row.position.to_string(),
+                )
+            })?;
+            let key = (row.token.clone(), row.position);
+            if let Some(previous) = self.tokens.insert(key, jamo.clone())
+                && previous != jamo
+            {
+                return Err(SpellingError::Conflict(
+                    name.into(),
+                    line,
+                    row.token,
+                    spelled(&previous),
+                    spelled(&jamo),
+                ));
+            }
+            let tree = match row.position {
+                Position::Any => &mut self.any,
+                Position::Initial => &mut self.initial,
+                Position::Final => &mut self.finale,
+            };
+            tree.insert_str(&row.token, jamo);
+        }
+        Ok(())
+    }
+
+    /// Every jamo `token` may spell when added to `syllable`, the
+    /// spelling of its position first
+    pub fn get(&self, token: &str, syllable: &Syllable) -> Vec<Jamo> {
+        let alias = match syllable.state() {
+            State::Start => self.initial.get_str(token),
+            State::Open | State::OpenFinal => self.finale.get_str(token),
+            State::Medial | State::End => None,
+        };
+        let mut ret = alias.unwrap_or_default();
+        for jamo in self.any.get_str(token).unwrap_or_default() {
+            if !ret.contains(&jamo) {
+                ret.push(jamo);
+            }
+        }
+        ret
+    }
+
+    fn jamo(row: &Row) -> Option<Vec<Jamo>> {
+        let valid = |jamo: Jamo| match row.position {
+            Position::Any => true,
+            Position::Initial => InitialJamo::try_from(jamo).is_ok(),
+            Position::Final => FinalJamo::try_from(jamo).is_ok(),
+        };
+        let ret = row
+            .jamo
+            .chars()
+            .map(|c| Jamo::try_from(c).ok().filter(|j| valid(*j)))
+            .collect::<Option<Vec<_>>>()?;
+        (!ret.is_empty()).then_some(ret)
+    }
+}
+
+fn spelled(jamo: &[Jamo]) -> String {
+    jamo.iter().map(char::from).collect()
+}
+
+/// The parser skips breaks and reads at most `MAX_TOKEN` characters
+fn reachable(token: &str) -> bool {
+    !token.is_empty()
+        && token.chars().count() <= MAX_TOKEN
+        && !token.starts_with(super::is_break)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SpellingError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Csv(#[from] csv::Error),
+    #[error("Scheme file {0} does not match a scheme, expected rr, mr or yale")]
+    UnknownScheme(String),
+    #[error("Scheme {0}, line {1}: token {2:?} can never be read")]
+    Unreachable(String, u64, String),
+    #[error("Scheme {0}, line {1}: {2:?} is not a jamo with position {3}")]
+    InvalidJamo(String, u64, String, String),
+    #[error("Scheme {0}, line {1}: token {2:?} is both {3} and {4}")]
+    Conflict(String, u64, String, String, String),
+}
+pub type SpellingResult<T> = Result<T, SpellingError>;
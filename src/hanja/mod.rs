@@ -0,0 +1,200 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use csv::ReaderBuilder;
+use serde::Deserialize;
+
+use crate::{
+    ext::single_char,
+    hangul::Hangul,
+    jamo::{FinalJamo, InitialJamo, MedialJamo},
+    syllable::Syllable,
+};
+
+const READINGS: &str = include_str!("readings.csv");
+
+/// Most readings returned for a word
+const MAX_READINGS: usize = 8;
+
+/// One line of the reading table
+#[derive(Debug, Deserialize)]
+struct Row {
+    hanja: String,
+    reading: String,
+}
+
+/// CJK unified and compatibility ideographs
+pub fn is_hanja(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3400}'..='\u{4dbf}'
+            | '\u{4e00}'..='\u{9fff}'
+            | '\u{f900}'..='\u{faff}'
+    )
+}
+
+/// ## Read
+/// Hangul readings of a word written in Hanja, most common first. Empty
+/// if a character has no known reading.
+///
+/// The initial sound law (두음법칙) is applied, e.g. `李` reads `이`
+/// and `勞動` reads `노동`.
+///
+/// The bundled table is partial, a few hundred common Hanja out of the
+/// 1,800 taught in schools (교육용 기초 한자), see [`known`].
+pub fn read(word: &str) -> Vec<Hangul> {
+    if word.is_empty() {
+        return vec![];
+    }
+    let mut ret = vec![Hangul::default()];
+    for c in word.chars() {
+        let syllables = match table().get(&c) {
+            Some(s) => s,
+            None => return vec![],
+        };
+        ret = ret
+            .iter()
+            .flat_map(|h| {
+                syllables.iter().map(|s| {
+                    let mut h = h.clone();
+                    h.push(*s);
+                    h
+                })
+            })
+            .take(MAX_READINGS)
+            .collect();
+    }
+    ret.into_iter().map(initial_sound_law).collect()
+}
+
+/// First character of `word` without a reading in the bundled table
+pub fn unknown(word: &str) -> Option<char> {
+    word.chars().find(|c| !table().contains_key(c))
+}
+
+/// Count of Hanja with a reading in the bundled table
+pub fn known() -> usize {
+    table().len()
+}
+
+/// ## Initial sound law
+/// `ㄹ` and `ㄴ` are not written at the start of a word, both become `ㅇ`
+/// before `ㅣ` or a `y` vowel and `ㄹ` otherwise becomes `ㄴ`. Inside a
+/// word `렬` and `률` are written `열` and `율` after a vowel or `ㄴ`,
+/// e.g. `羅列` reads `나열`.
+fn initial_sound_law(mut hangul: Hangul) -> Hangul {
+    if let Some(first) = hangul.first_mut() {
+        word_initial(first);
+    }
+    for i in 1..hangul.len() {
+        let previous = hangul[i - 1].finale();
+        let syllable = &mut hangul[i];
+        if syllable.initial() == Some(InitialJamo::R)
+            && matches!(
+                syllable.medial(),
+                Some(MedialJamo::Yeo | MedialJamo::Yu)
+            )
+            && syllable.finale() == Some(FinalJamo::R)
+            && matches!(previous, None | Some(FinalJamo::N))
+        {
+            syllable.set_initial(InitialJamo::Ng);
+        }
+    }
+    hangul
+}
+
+fn word_initial(syllable: &mut Syllable) {
+    let palatal = matches!(
+        syllable.medial(),
+        Some(
+            MedialJamo::Ya
+                | MedialJamo::Yae
+                | MedialJamo::Yeo
+                | MedialJamo::Ye
+                | MedialJamo::Yo
+                | MedialJamo::Yu
+                | MedialJamo::I
+        )
+    );
+    match syllable.initial() {
+        Some(InitialJamo::R | InitialJamo::N) if palatal => {
+            syllable.set_initial(InitialJamo::Ng)
+        }
+        Some(InitialJamo::R) => syllable.set_initial(InitialJamo::N),
+        _ => (),
+    }
+}
+
+/// Readings of every Hanja in the bundled table
+fn table() -> &'static HashMap<char, Vec<Syllable>> {
+    static TABLE: OnceLock<HashMap<char, Vec<Syllable>>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut ret: HashMap<char, Vec<Syllable>> = HashMap::new();
+        let mut rdr = ReaderBuilder::new()
+            .delimiter(b';')
+            .comment(Some(b'#'))
+            .from_reader(READINGS.as_bytes());
+        for row in rdr.deserialize::<Row>() {
+            let row = row.expect("Invalid Hanja table");
+            let hanja = single_char(&row.hanja);
+            let reading = single_char(&row.reading)
+                .and_then(|c| Syllable::try_from(c).ok());
+            match (hanja, reading) {
+                (Some(h), Some(r)) => ret.entry(h).or_default().push(r),
+                _ => panic!("Invalid Hanja table row: {:?}", row),
+            }
+        }
+        ret
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hanja::{is_hanja, known, read, table, unknown};
+
+    fn first(word: &str) -> String {
+        read(word)
+            .first()
+            .map(|h| h.to_string())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_read() {
+        assert!(table().keys().all(|c| is_hanja(*c)));
+        assert_eq!(first("韓國"), "한국");
+        assert_eq!(first("韓國語"), "한국어");
+        assert_eq!(first("漢字"), "한자");
+        assert_eq!(first("X"), "");
+        assert_eq!(unknown("韓X國"), Some('X'));
+        assert_eq!(unknown("韓國"), None);
+        let readings = read("音樂");
+        assert!(readings.iter().any(|h| h.to_string() == "음악"));
+    }
+
+    #[test]
+    fn test_coverage() {
+        // The UI tells the table is partial, which it should stay true to
+        assert!((250..1800).contains(&known()), "{}", known());
+        let common = "一二三四五六七八九十百千萬日月火水木金土\
+                      人大小中上下山川年生學校國韓先南北東西";
+        for c in common.chars() {
+            assert_eq!(unknown(&c.to_string()), None, "{}", c);
+        }
+    }
+
+    #[test]
+    fn test_initial_sound_law() {
+        assert_eq!(first("李"), "이");
+        assert_eq!(first("勞動"), "노동");
+        assert_eq!(first("老人"), "노인");
+        assert_eq!(first("女子"), "여자");
+        assert_eq!(first("歷史"), "역사");
+        assert_eq!(first("來日"), "내일");
+        // Only at the start of a word
+        assert_eq!(first("敎理"), "교리");
+        assert_eq!(first("男女"), "남녀");
+        assert_eq!(first("羅列"), "나열");
+        assert_eq!(first("比率"), "비율");
+        assert_eq!(first("法律"), "법률");
+    }
+}
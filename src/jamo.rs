@@ -307,9 +307,9 @@ impl TryFrom<char> for Jamo {
     type Error = JamoError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
-        let code = value as u16;
-        if Self::G as u16 <= code && code <= Self::I as u16 {
-            Ok(unsafe { transmute(code) })
+        let code = value as u32;
+        if Self::G as u32 <= code && code <= Self::I as u32 {
+            Ok(unsafe { transmute::<u16, Jamo>(code as u16) })
         } else {
             Err(JamoError::InvalidChar(value))
         }
@@ -531,6 +531,19 @@ impl TryFrom<FinalJamo> for InitialJamo {
         }
     }
 }
+/// Conjoining jamo, i.e. the decomposed (NFD) form of a syllable
+impl TryFrom<char> for InitialJamo {
+    type Error = JamoError;
+
+    fn try_from(value: char) -> JamoResult<Self> {
+        let code = value as u32;
+        if (Self::G as u32..=Self::H as u32).contains(&code) {
+            Ok(unsafe { transmute::<u16, InitialJamo>(code as u16) })
+        } else {
+            Err(JamoError::InvalidChar(value))
+        }
+    }
+}
 impl From<InitialJamo> for char {
     fn from(value: InitialJamo) -> Self {
         // Safe ! all variants of jamo have valid unicode values
@@ -704,10 +717,7 @@ impl MedialJamo {
                 return other.combine(self);
             }
             _ => {
-                return Err(JamoError::IncompatibleCombine(
-                    self.into(),
-                    other.into(),
-                ));
+                return Err(JamoError::IncompatibleCombine(self, other));
             }
         };
 
@@ -798,6 +808,19 @@ impl TryFrom<Jamo> for MedialJamo {
         }
     }
 }
+/// Conjoining jamo, i.e. the decomposed (NFD) form of a syllable
+impl TryFrom<char> for MedialJamo {
+    type Error = JamoError;
+
+    fn try_from(value: char) -> JamoResult<Self> {
+        let code = value as u32;
+        if (Self::A as u32..=Self::I as u32).contains(&code) {
+            Ok(unsafe { transmute::<u16, MedialJamo>(code as u16) })
+        } else {
+            Err(JamoError::InvalidChar(value))
+        }
+    }
+}
 impl From<MedialJamo> for char {
     fn from(value: MedialJamo) -> Self {
         // Safe ! all variants of jamo have valid unicode values
@@ -1095,6 +1118,19 @@ impl TryFrom<InitialJamo> for FinalJamo {
         }
     }
 }
+/// Conjoining jamo, i.e. the decomposed (NFD) form of a syllable
+impl TryFrom<char> for FinalJamo {
+    type Error = JamoError;
+
+    fn try_from(value: char) -> JamoResult<Self> {
+        let code = value as u32;
+        if (Self::G as u32..=Self::H as u32).contains(&code) {
+            Ok(unsafe { transmute::<u16, FinalJamo>(code as u16) })
+        } else {
+            Err(JamoError::InvalidChar(value))
+        }
+    }
+}
 impl From<FinalJamo> for char {
     fn from(value: FinalJamo) -> Self {
         // Safe ! all variants of jamo have valid unicode values
@@ -0,0 +1,265 @@
+use std::{
+    collections::{HashMap, HashSet},
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use csv::ReaderBuilder;
+use serde::Deserialize;
+
+use crate::{
+    ext::single_char,
+    jamo::{FinalJamo, InitialJamo, Jamo, MedialJamo},
+};
+
+const DUBEOLSIK: &str = include_str!("layouts/dubeolsik.csv");
+const SEBEOLSIK_390: &str = include_str!("layouts/sebeolsik-390.csv");
+const SEBEOLSIK_FINAL: &str = include_str!("layouts/sebeolsik-final.csv");
+
+/// ## Key
+/// Jamo typed by a key. `Any` leaves the position to the syllable being
+/// typed, as on Dubeolsik where one key types both `ㄱ` initial and final.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Any(Jamo),
+    Initial(InitialJamo),
+    Medial(MedialJamo),
+    Final(FinalJamo),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Position {
+    Any,
+    Initial,
+    Medial,
+    Final,
+}
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Position::Any => "any",
+            Position::Initial => "initial",
+            Position::Medial => "medial",
+            Position::Final => "final",
+        })
+    }
+}
+
+/// One line of a layout file
+#[derive(Debug, Deserialize)]
+struct Row {
+    key: String,
+    position: Position,
+    jamo: String,
+}
+
+/// ## Layout
+/// Korean keyboard layout, maps the character of a QWERTY key press to
+/// the jamo at the same position.
+///
+/// Layouts are defined in `;` separated files with the header
+/// `key;position;jamo`, where position is one of `any`, `initial`,
+/// `medial` or `final` and jamo is a compatibility jamo, e.g. `ㄱ`.
+/// Lines starting with `#` are comments.
+#[derive(Debug, Clone)]
+pub struct Layout {
+    name: String,
+    keys: HashMap<char, Key>,
+    /// Whether Shift only doubles some keys, as on Dubeolsik, so other
+    /// shifted keys type their unshifted jamo
+    shift_doubles: bool,
+}
+impl Layout {
+    /// Shipped layouts followed by the custom layouts in `$CWD/layouts`,
+    /// named after their file
+    pub fn load_all() -> LayoutResult<Vec<Layout>> {
+        let mut ret = Self::builtin()?;
+        let dir = Self::dir()?;
+        if !dir.is_dir() {
+            return Ok(ret);
+        }
+        let mut paths = fs::read_dir(dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        paths.sort();
+        for path in paths {
+            if path.extension().is_some_and(|e| e == "csv") {
+                ret.push(Self::load(&path)?);
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Dubeolsik, Sebeolsik 390 and Sebeolsik Final
+    pub fn builtin() -> LayoutResult<Vec<Layout>> {
+        [
+            ("2set", DUBEOLSIK),
+            ("390", SEBEOLSIK_390),
+            ("3fin", SEBEOLSIK_FINAL),
+        ]
+        .into_iter()
+        .map(|(name, def)| Self::parse(name, def.as_bytes()))
+        .collect()
+    }
+
+    pub fn load(path: &Path) -> LayoutResult<Layout> {
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Self::parse(&name, fs::File::open(path)?)
+    }
+
+    /// Reads a layout definition and checks that it can type every jamo
+    pub fn parse(
+        name: &str,
+        definition: impl io::Read,
+    ) -> LayoutResult<Layout> {
+        let mut rdr = ReaderBuilder::new()
+            .delimiter(b';')
+            .comment(Some(b'#'))
+            .from_reader(definition);
+        let mut keys = HashMap::new();
+        for record in rdr.records() {
+            let record = record?;
+            let line = record.position().map(|p| p.line()).unwrap_or(0);
+            let row: Row = record.deserialize(None)?;
+            let key = single_char(&row.key).ok_or_else(|| {
+                LayoutError::InvalidKey(name.into(), line, row.key.clone())
+            })?;
+            let value = Self::key(&row).ok_or_else(|| {
+                LayoutError::InvalidJamo(
+                    name.into(),
+                    line,
+                    row.jamo.clone(),
+                    row.position.to_string(),
+                )
+            })?;
+            if keys.insert(key, value).is_some() {
+                return Err(LayoutError::DuplicateKey(name.into(), line, key));
+            }
+        }
+        // Layouts with keys for each position put other jamo on Shift
+        let shift_doubles = keys.values().all(|k| matches!(k, Key::Any(_)));
+        let layout = Self {
+            name: name.into(),
+            keys,
+            shift_doubles,
+        };
+        layout.validate()?;
+        Ok(layout)
+    }
+
+    /// Short name shown next to the input
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// On Dubeolsik shifted keys without a jamo of their own fall back
+    /// to the unshifted key, on Sebeolsik they type no jamo
+    pub fn get(&self, key: char) -> Option<Key> {
+        self.keys
+            .get(&key)
+            .or_else(|| match self.shift_doubles {
+                true => self.keys.get(&key.to_ascii_lowercase()),
+                false => None,
+            })
+            .copied()
+    }
+
+    fn key(row: &Row) -> Option<Key> {
+        let jamo = Jamo::try_from(single_char(&row.jamo)?).ok()?;
+        match row.position {
+            Position::Any => Some(Key::Any(jamo)),
+            Position::Initial => {
+                InitialJamo::try_from(jamo).ok().map(Key::Initial)
+            }
+            Position::Medial => {
+                MedialJamo::try_from(jamo).ok().map(Key::Medial)
+            }
+            Position::Final => FinalJamo::try_from(jamo).ok().map(Key::Final),
+        }
+    }
+
+    /// Every jamo must be typed either by a key of its own or by
+    /// combining the jamo of other keys
+    fn validate(&self) -> LayoutResult<()> {
+        let mut initials = HashSet::new();
+        let mut medials = HashSet::new();
+        let mut finals = HashSet::new();
+        for key in self.keys.values() {
+            match *key {
+                Key::Any(j) => {
+                    initials.extend(InitialJamo::try_from(j).ok());
+                    medials.extend(MedialJamo::try_from(j).ok());
+                    finals.extend(FinalJamo::try_from(j).ok());
+                }
+                Key::Initial(ij) => {
+                    initials.insert(ij);
+                    initials.extend(super::double(ij, ij));
+                }
+                Key::Medial(mj) => {
+                    medials.insert(mj);
+                }
+                Key::Final(fj) => {
+                    finals.insert(fj);
+                }
+            }
+        }
+        for (m1, m2) in pairs(&medials) {
+            medials.extend(m1.combine(m2).ok());
+        }
+        for (f1, f2) in pairs(&finals) {
+            finals.extend(f1.append(f2).ok());
+        }
+
+        let missing = |jamo: Jamo, position: Position| {
+            LayoutError::Missing(self.name.clone(), position.to_string(), jamo)
+        };
+        if let Some(ij) = InitialJamo::all()
+            .into_iter()
+            .find(|j| !initials.contains(j))
+        {
+            return Err(missing(ij.into(), Position::Initial));
+        }
+        if let Some(mj) =
+            MedialJamo::all().into_iter().find(|j| !medials.contains(j))
+        {
+            return Err(missing(mj.into(), Position::Medial));
+        }
+        if let Some(fj) =
+            FinalJamo::all().into_iter().find(|j| !finals.contains(j))
+        {
+            return Err(missing(fj.into(), Position::Final));
+        }
+        Ok(())
+    }
+
+    fn dir() -> io::Result<PathBuf> {
+        Ok(env::current_dir()?.join("layouts"))
+    }
+}
+
+fn pairs<T: Copy>(set: &HashSet<T>) -> Vec<(T, T)> {
+    set.iter()
+        .flat_map(|a| set.iter().map(move |b| (*a, *b)))
+        .collect()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LayoutError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Csv(#[from] csv::Error),
+    #[error("Layout {0}, line {1}: key must be a single character, got {2:?}")]
+    InvalidKey(String, u64, String),
+    #[error("Layout {0}, line {1}: {2:?} is not a jamo with position {3}")]
+    InvalidJamo(String, u64, String, String),
+    #[error("Layout {0}, line {1}: key {2:?} is defined more than once")]
+    DuplicateKey(String, u64, char),
+    #[error("Layout {0} can not type {1} {2}")]
+    Missing(String, String, Jamo),
+}
+pub type LayoutResult<T> = Result<T, LayoutError>;
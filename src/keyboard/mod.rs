@@ -0,0 +1,179 @@
+mod layout;
+
+pub use layout::{Key, Layout};
+
+use std::mem;
+
+use crate::{
+    jamo::{FinalJamo, InitialJamo, Jamo, MedialJamo},
+    syllable::Syllable,
+};
+
+/// ## Compose
+/// Adds the jamo of `key` to the syllable being typed like an IME
+/// would. Returns the finished syllable if `key` starts a new one.
+pub fn compose(syllable: &mut Syllable, key: Key) -> Option<Syllable> {
+    match key {
+        Key::Any(jamo) => compose_any(syllable, jamo),
+        Key::Initial(ij) => compose_initial(syllable, ij),
+        Key::Medial(mj) => compose_medial(syllable, mj),
+        Key::Final(fj) => compose_final(syllable, fj),
+    }
+}
+
+/// A vowel takes the last final consonant as its initial, e.g. `각`
+/// followed by `ㅏ` becomes `가가`
+fn compose_any(syllable: &mut Syllable, jamo: Jamo) -> Option<Syllable> {
+    let next = match syllable.push(jamo) {
+        Ok(None) => return None,
+        Ok(Some(next)) => next,
+        // Consonant after a lone consonant
+        Err(_) => Syllable::try_from(jamo).unwrap_or_default(),
+    };
+    let mut done = *syllable;
+    *syllable = next;
+    if let Ok(mj) = MedialJamo::try_from(jamo)
+        && done.finale().is_some()
+        && let Some(j) = done.pop()
+        && let Ok(ij) = InitialJamo::try_from(j)
+    {
+        *syllable = Syllable::new(ij, mj, None);
+    }
+    Some(done)
+}
+
+/// Typing a plain initial twice gives its tense form
+fn compose_initial(
+    syllable: &mut Syllable,
+    initial: InitialJamo,
+) -> Option<Syllable> {
+    if let Some(ij) = syllable.initial()
+        && syllable.medial().is_none()
+        && let Some(tense) = double(ij, initial)
+    {
+        syllable.set_initial(tense);
+        return None;
+    }
+    start(syllable, initial.into())
+}
+
+fn compose_medial(
+    syllable: &mut Syllable,
+    medial: MedialJamo,
+) -> Option<Syllable> {
+    if syllable.is_empty() || syllable.finale().is_some() {
+        return start(syllable, medial.into());
+    }
+    match syllable.push(medial.into()) {
+        Ok(Some(next)) => start(syllable, next),
+        _ => None,
+    }
+}
+
+/// A final without a vowel before it is typed as an initial
+fn compose_final(
+    syllable: &mut Syllable,
+    finale: FinalJamo,
+) -> Option<Syllable> {
+    if syllable.medial().is_some() {
+        match syllable.finale() {
+            None => {
+                syllable.set_finale(Some(finale));
+                return None;
+            }
+            Some(fj) => {
+                if let Ok(compound) = fj.append(finale) {
+                    syllable.set_finale(Some(compound));
+                    return None;
+                }
+            }
+        }
+    }
+    match InitialJamo::try_from(finale) {
+        Ok(ij) => start(syllable, ij.into()),
+        Err(_) => None,
+    }
+}
+
+/// Replaces `syllable` with `next`, returning it unless it was empty
+fn start(syllable: &mut Syllable, next: Syllable) -> Option<Syllable> {
+    let done = mem::replace(syllable, next);
+    if done.is_empty() { None } else { Some(done) }
+}
+
+fn double(first: InitialJamo, second: InitialJamo) -> Option<InitialJamo> {
+    match (first, second) {
+        (InitialJamo::G, InitialJamo::G) => Some(InitialJamo::Gg),
+        (InitialJamo::D, InitialJamo::D) => Some(InitialJamo::Dd),
+        (InitialJamo::B, InitialJamo::B) => Some(InitialJamo::Bb),
+        (InitialJamo::S, InitialJamo::S) => Some(InitialJamo::Ss),
+        (InitialJamo::J, InitialJamo::J) => Some(InitialJamo::Jj),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        hangul::Hangul,
+        keyboard::{Layout, compose},
+        syllable::Syllable,
+    };
+
+    fn typed(layout: &str, keys: &str) -> String {
+        let layouts = Layout::builtin().unwrap();
+        let layout = layouts.iter().find(|l| l.name() == layout).unwrap();
+        let mut hangul = Hangul::default();
+        let mut syllable = Syllable::default();
+        for k in keys.chars() {
+            let key = layout.get(k).unwrap();
+            if let Some(done) = compose(&mut syllable, key) {
+                hangul.push(done);
+            }
+        }
+        hangul.push(syllable);
+        hangul.to_string()
+    }
+
+    #[test]
+    fn test_dubeolsik() {
+        assert_eq!(typed("2set", "dkssudgktpdy"), "안녕하세요");
+        assert_eq!(typed("2set", "rkrk"), "가가");
+        assert_eq!(typed("2set", "Rhcl"), "꼬치");
+        assert_eq!(typed("2set", "dlfrdj"), "읽어");
+        assert_eq!(typed("2set", "dlfrj"), "일거");
+        assert_eq!(typed("2set", "ghkdl"), "화이");
+        assert_eq!(typed("2set", "GKS"), "한");
+    }
+
+    #[test]
+    fn test_shift_fallback() {
+        let layouts = Layout::builtin().unwrap();
+        for layout in layouts.iter().filter(|l| l.name() != "2set") {
+            // Shifted keys type other jamo, so a missing one types none
+            assert!(layout.get('j').is_some(), "{}", layout.name());
+            assert_eq!(layout.get('J'), None, "{}", layout.name());
+        }
+    }
+
+    #[test]
+    fn test_sebeolsik() {
+        for layout in ["390", "3fin"] {
+            assert_eq!(typed(layout, "jfsjgs"), "안은", "{}", layout);
+            assert_eq!(typed(layout, "kfxjt"), "각어", "{}", layout);
+            assert_eq!(typed(layout, "kkvjfa"), "꼬앙", "{}", layout);
+            assert_eq!(typed(layout, "mvf"), "화", "{}", layout);
+            assert_eq!(typed(layout, "ygwx"), "륽", "{}", layout);
+        }
+    }
+
+    #[test]
+    fn test_invalid_layout() {
+        let missing = "key;position;jamo\nr;any;ㄱ\n";
+        assert!(Layout::parse("missing", missing.as_bytes()).is_err());
+        let invalid = "key;position;jamo\nr;medial;ㄱ\n";
+        assert!(Layout::parse("invalid", invalid.as_bytes()).is_err());
+        let duplicate = "key;position;jamo\nr;any;ㄱ\nr;any;ㄴ\n";
+        assert!(Layout::parse("duplicate", duplicate.as_bytes()).is_err());
+    }
+}
@@ -1,16 +1,39 @@
 // #![allow(dead_code)]
 
+use std::{env, process};
+
 use terminal::{Terminal, TerminalResult, code::TerminalCode};
 
-use crate::scenes::{MainItems, help_menu_scene, main_scene, menu_scene};
+use crate::{
+    args::Args,
+    deck::{DEFAULT_DECK, Decks},
+    hangul_parser::HangulParser,
+    keyboard::Layout,
+    scenes::{MainItems, help_menu_scene, main_scene, menu_scene},
+};
 
+mod args;
+mod backup;
+mod collation;
+mod conjugation;
+mod deck;
 mod elements;
 mod ext;
 mod hangul;
 mod hangul_parser;
+mod hanja;
 mod jamo;
+mod keyboard;
+mod numbers;
+mod particles;
+mod pattern;
+mod pronunciation;
+mod romanization;
 mod scenes;
+mod schema;
+mod search;
 mod syllable;
+mod text;
 
 use terminal::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 
@@ -37,7 +60,49 @@ macro_rules! ctrl {
 // 4:3 becomes 8:3
 fn main() -> TerminalResult<()> {
     pretty_env_logger::init();
-    let (main_scene, scenes, MainItems { log, .. }) = main_scene((81, 31))?;
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, Args::USAGE);
+            process::exit(2);
+        }
+    };
+    let parser = match HangulParser::load(args.scheme) {
+        Ok(parser) => parser,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+    let layouts = match Layout::load_all() {
+        Ok(layouts) => layouts,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+    let decks = match Decks::open(args.data_dir) {
+        Ok(decks) => decks,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+    // A deck named on the command line is created if new
+    let deck = args.deck.unwrap_or_else(|| DEFAULT_DECK.into());
+    let deck_path = match decks.exists(&deck) {
+        true => decks.path(&deck),
+        false => decks.create(&deck),
+    };
+    let deck_path = match deck_path {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+    let (main_scene, scenes, MainItems { log, .. }) =
+        main_scene((81, 31), deck_path, &parser, &layouts)?;
     let main_log = log.clone();
     let mut term = Terminal::new(
         "main".into(),
@@ -49,7 +114,7 @@ fn main() -> TerminalResult<()> {
             _ => TerminalCode::UnhandledKey(k),
         },
         move || {
-            main_log.read().unwrap().save()?;
+            main_log.read().unwrap().save_if_read()?;
             Ok(())
         },
     );
@@ -59,7 +124,7 @@ fn main() -> TerminalResult<()> {
     }
     term.insert_scene("help".into(), help_menu_scene()?);
 
-    let (menu_scene, scenes) = menu_scene((81, 31), log)?;
+    let (menu_scene, scenes) = menu_scene(log, decks, deck, &parser, &layouts)?;
     term.insert_scene("menu".into(), menu_scene);
     for (name, scene) in scenes {
         term.insert_scene(name, scene);
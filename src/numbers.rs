@@ -0,0 +1,277 @@
+use crate::{hangul::Hangul, text::Text};
+
+const SINO_DIGITS: [&str; 10] =
+    ["", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구"];
+const SINO_UNITS: [&str; 4] = ["", "십", "백", "천"];
+/// Every fourth power of ten has a name of its own
+const SINO_GROUPS: [&str; 5] = ["", "만", "억", "조", "경"];
+const NATIVE_ONES: [&str; 10] = [
+    "", "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+/// Forms before a counter
+const NATIVE_ONES_COUNTING: [&str; 10] = [
+    "", "한", "두", "세", "네", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+const NATIVE_TENS: [&str; 10] = [
+    "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+];
+
+/// ## Counter
+/// Word counted after a number, read with native numbers up to 99 or
+/// with Sino-Korean numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Counter {
+    /// 개, things
+    Gae,
+    /// 명, people
+    Myeong,
+    /// 살, years of age
+    Sal,
+    /// 마리, animals
+    Mari,
+    /// 권, books
+    Gwon,
+    /// 잔, cups
+    Jan,
+    /// 번, times
+    Beon,
+    /// 시, hours of the clock
+    Si,
+    /// 분, minutes
+    Bun,
+    /// 원, won
+    Won,
+    /// 층, floors
+    Cheung,
+}
+impl Counter {
+    pub fn all() -> Vec<Counter> {
+        vec![
+            Counter::Gae,
+            Counter::Myeong,
+            Counter::Sal,
+            Counter::Mari,
+            Counter::Gwon,
+            Counter::Jan,
+            Counter::Beon,
+            Counter::Si,
+            Counter::Bun,
+            Counter::Won,
+            Counter::Cheung,
+        ]
+    }
+
+    pub fn word(self) -> &'static str {
+        match self {
+            Counter::Gae => "개",
+            Counter::Myeong => "명",
+            Counter::Sal => "살",
+            Counter::Mari => "마리",
+            Counter::Gwon => "권",
+            Counter::Jan => "잔",
+            Counter::Beon => "번",
+            Counter::Si => "시",
+            Counter::Bun => "분",
+            Counter::Won => "원",
+            Counter::Cheung => "층",
+        }
+    }
+
+    /// Counted with native numbers
+    pub fn is_native(self) -> bool {
+        !matches!(self, Counter::Bun | Counter::Won | Counter::Cheung)
+    }
+}
+
+/// ## Sino-Korean
+/// e.g. 231 reads `이백삼십일`. A leading one is not read before `십`,
+/// `백`, `천` and `만`.
+pub fn sino(n: u64) -> Hangul {
+    hangul(&sino_str(n))
+}
+
+/// ## Native Korean
+/// e.g. 21 reads `스물하나`, only defined from 1 to 99
+pub fn native(n: u64) -> Option<Hangul> {
+    native_str(n, false).map(|s| hangul(&s))
+}
+
+/// ## Count
+/// `n` followed by `counter`, e.g. `한 개`, `스무 살` or `삼십 분`
+pub fn count(n: u64, counter: Counter) -> Text {
+    let number = match counter.is_native() {
+        true => native_str(n, true).unwrap_or_else(|| sino_str(n)),
+        false => sino_str(n),
+    };
+    Text::from(format!("{} {}", number, counter.word()).as_str())
+}
+
+/// ## Date
+/// e.g. `이천이십육 년 시월 십칠 일`, `None` for a month or day which
+/// does not exist
+pub fn date(year: u64, month: u64, day: u64) -> Option<Text> {
+    let leap = year.is_multiple_of(4)
+        && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+    if !(1..=days).contains(&day) {
+        return None;
+    }
+    // 육월 and 십월 lose their final
+    let month = match month {
+        6 => "유월".to_string(),
+        10 => "시월".to_string(),
+        m => format!("{}월", sino_str(m)),
+    };
+    let date = format!("{} 년 {} {} 일", sino_str(year), month, sino_str(day));
+    Some(Text::from(date.as_str()))
+}
+
+/// ## Time
+/// Hours are counted natively and minutes in Sino-Korean, e.g.
+/// `세 시 이십 분`. Takes a 24-hour clock.
+pub fn time(hour: u64, minute: u64) -> Option<Text> {
+    if hour > 23 || minute > 59 {
+        return None;
+    }
+    let hour = match hour % 12 {
+        0 => 12,
+        h => h,
+    };
+    let hour = count(hour, Counter::Si);
+    Some(match minute {
+        0 => hour,
+        m => {
+            Text::from(format!("{} {}", hour, count(m, Counter::Bun)).as_str())
+        }
+    })
+}
+
+fn sino_str(n: u64) -> String {
+    if n == 0 {
+        return "영".into();
+    }
+    let mut groups = vec![];
+    let mut rest = n;
+    while rest > 0 {
+        groups.push(rest % 10_000);
+        rest /= 10_000;
+    }
+    let mut ret = String::new();
+    for (i, group) in groups.into_iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        if !(i == 1 && group == 1) {
+            ret.push_str(&sino_group(group));
+        }
+        ret.push_str(SINO_GROUPS[i]);
+    }
+    ret
+}
+
+/// Reads a number below 10 000
+fn sino_group(n: u64) -> String {
+    let mut ret = String::new();
+    for unit in (0..4).rev() {
+        let digit = (n / 10u64.pow(unit as u32) % 10) as usize;
+        if digit == 0 {
+            continue;
+        }
+        if digit != 1 || unit == 0 {
+            ret.push_str(SINO_DIGITS[digit]);
+        }
+        ret.push_str(SINO_UNITS[unit]);
+    }
+    ret
+}
+
+fn native_str(n: u64, counting: bool) -> Option<String> {
+    if !(1..=99).contains(&n) {
+        return None;
+    }
+    let (tens, ones) = ((n / 10) as usize, (n % 10) as usize);
+    // 스물 loses its final before a counter
+    if counting && n == 20 {
+        return Some("스무".into());
+    }
+    let ones = match counting {
+        true => NATIVE_ONES_COUNTING[ones],
+        false => NATIVE_ONES[ones],
+    };
+    Some(format!("{}{}", NATIVE_TENS[tens], ones))
+}
+
+fn hangul(s: &str) -> Hangul {
+    Hangul::try_from(s).expect("Logic error!")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::numbers::{Counter, count, date, native, sino, time};
+
+    #[test]
+    fn test_sino() {
+        let sino = |n| sino(n).to_string();
+        assert_eq!(sino(0), "영");
+        assert_eq!(sino(16), "십육");
+        assert_eq!(sino(231), "이백삼십일");
+        assert_eq!(sino(1_111), "천백십일");
+        assert_eq!(sino(10_000), "만");
+        assert_eq!(sino(21_000), "이만천");
+        assert_eq!(sino(100_000_000), "일억");
+        assert_eq!(sino(2026), "이천이십육");
+    }
+
+    #[test]
+    fn test_native() {
+        let native = |n| native(n).map(|h| h.to_string());
+        assert_eq!(native(1).as_deref(), Some("하나"));
+        assert_eq!(native(21).as_deref(), Some("스물하나"));
+        assert_eq!(native(99).as_deref(), Some("아흔아홉"));
+        assert_eq!(native(0), None);
+        assert_eq!(native(100), None);
+    }
+
+    #[test]
+    fn test_count() {
+        let count = |n, c| count(n, c).to_string();
+        assert_eq!(count(1, Counter::Gae), "한 개");
+        assert_eq!(count(2, Counter::Myeong), "두 명");
+        assert_eq!(count(3, Counter::Jan), "세 잔");
+        assert_eq!(count(20, Counter::Sal), "스무 살");
+        assert_eq!(count(21, Counter::Sal), "스물한 살");
+        assert_eq!(count(100, Counter::Gae), "백 개");
+        assert_eq!(count(30, Counter::Bun), "삼십 분");
+    }
+
+    #[test]
+    fn test_date_and_time() {
+        let date = |y, m, d| date(y, m, d).map(|t| t.to_string());
+        assert_eq!(
+            date(2026, 10, 17).as_deref(),
+            Some("이천이십육 년 시월 십칠 일")
+        );
+        assert_eq!(
+            date(2026, 6, 1).as_deref(),
+            Some("이천이십육 년 유월 일 일")
+        );
+        assert_eq!(date(2026, 13, 1), None);
+        assert_eq!(date(2026, 2, 31), None);
+        assert_eq!(date(2026, 4, 31), None);
+        assert_eq!(date(2026, 2, 29), None);
+        assert!(date(2024, 2, 29).is_some());
+        assert_eq!(date(1900, 2, 29), None);
+        assert!(date(2000, 2, 29).is_some());
+        let time = |h, m| time(h, m).map(|t| t.to_string());
+        assert_eq!(time(3, 20).as_deref(), Some("세 시 이십 분"));
+        assert_eq!(time(15, 0).as_deref(), Some("세 시"));
+        assert_eq!(time(0, 5).as_deref(), Some("열두 시 오 분"));
+        assert_eq!(time(24, 0), None);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::hangul::Hangul;
+
+/// ## Particle
+/// Postposition whose form depends on whether the word before it ends
+/// in a consonant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Particle {
+    /// 은/는
+    Topic,
+    /// 이/가
+    Subject,
+    /// 을/를
+    Object,
+    /// 와/과
+    And,
+    /// (으)로, `로` after a vowel or `ㄹ`
+    Direction,
+    /// 이에요/예요
+    Copula,
+    /// 아/야
+    Vocative,
+}
+impl Particle {
+    pub fn all() -> [Particle; 7] {
+        [
+            Particle::Topic,
+            Particle::Subject,
+            Particle::Object,
+            Particle::And,
+            Particle::Direction,
+            Particle::Copula,
+            Particle::Vocative,
+        ]
+    }
+
+    /// Both forms, e.g. `은/는`
+    pub fn name(self) -> &'static str {
+        match self {
+            Particle::Topic => "은/는",
+            Particle::Subject => "이/가",
+            Particle::Object => "을/를",
+            Particle::And => "와/과",
+            Particle::Direction => "(으)로",
+            Particle::Copula => "이에요/예요",
+            Particle::Vocative => "아/야",
+        }
+    }
+
+    /// Form of the particle after `word`
+    pub fn form(self, word: &Hangul) -> &'static str {
+        let consonant = word.ends_in_consonant();
+        match self {
+            Particle::Topic if consonant => "은",
+            Particle::Topic => "는",
+            Particle::Subject if consonant => "이",
+            Particle::Subject => "가",
+            Particle::Object if consonant => "을",
+            Particle::Object => "를",
+            Particle::And if consonant => "과",
+            Particle::And => "와",
+            Particle::Direction if consonant && !word.ends_in_r() => "으로",
+            Particle::Direction => "로",
+            Particle::Copula if consonant => "이에요",
+            Particle::Copula => "예요",
+            Particle::Vocative if consonant => "아",
+            Particle::Vocative => "야",
+        }
+    }
+
+    /// `word` followed by the particle, e.g. `책을`
+    pub fn attach(self, word: &Hangul) -> Hangul {
+        let mut ret = word.clone();
+        ret.extend(
+            Hangul::try_from(self.form(word))
+                .expect("Logic error!")
+                .iter(),
+        );
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hangul::Hangul, particles::Particle};
+
+    fn attach(word: &str, particle: Particle) -> String {
+        particle
+            .attach(&Hangul::try_from(word).unwrap())
+            .to_string()
+    }
+
+    #[test]
+    fn test_attach() {
+        assert_eq!(attach("책", Particle::Topic), "책은");
+        assert_eq!(attach("나무", Particle::Topic), "나무는");
+        assert_eq!(attach("물", Particle::Subject), "물이");
+        assert_eq!(attach("차", Particle::Subject), "차가");
+        assert_eq!(attach("밥", Particle::Object), "밥을");
+        assert_eq!(attach("커피", Particle::Object), "커피를");
+        assert_eq!(attach("빵", Particle::And), "빵과");
+        assert_eq!(attach("우유", Particle::And), "우유와");
+        assert_eq!(attach("집", Particle::Direction), "집으로");
+        assert_eq!(attach("학교", Particle::Direction), "학교로");
+        assert_eq!(attach("지하철", Particle::Direction), "지하철로");
+        assert_eq!(attach("학생", Particle::Copula), "학생이에요");
+        assert_eq!(attach("의사", Particle::Copula), "의사예요");
+        assert_eq!(attach("민준", Particle::Vocative), "민준아");
+        assert_eq!(attach("지우", Particle::Vocative), "지우야");
+    }
+}
@@ -0,0 +1,265 @@
+use std::iter;
+
+use crate::{
+    hangul::Hangul,
+    jamo::{FinalJamo, InitialJamo, Jamo, MedialJamo},
+    syllable::Syllable,
+};
+
+/// ## Syllable Pattern
+/// Jamo allowed in each slot of a syllable, `None` allowing any. A final
+/// slot of `[None]` only allows syllables without a final.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyllablePattern {
+    initial: Option<Vec<InitialJamo>>,
+    medial: Option<Vec<MedialJamo>>,
+    finale: Option<Vec<Option<FinalJamo>>>,
+}
+impl SyllablePattern {
+    pub fn matches(&self, syllable: &Syllable) -> bool {
+        match (syllable.initial(), syllable.medial()) {
+            (Some(i), Some(m)) => {
+                allows(&self.initial, &i)
+                    && allows(&self.medial, &m)
+                    && allows(&self.finale, &syllable.finale())
+            }
+            _ => false,
+        }
+    }
+
+    /// Every complete syllable, out of all 11,172, matching the pattern
+    pub fn syllables(&self) -> impl Iterator<Item = Syllable> + '_ {
+        InitialJamo::all()
+            .into_iter()
+            .flat_map(|i| {
+                MedialJamo::all().into_iter().flat_map(move |m| {
+                    iter::once(None)
+                        .chain(FinalJamo::all().into_iter().map(Some))
+                        .map(move |f| Syllable::new(i, m, f))
+                })
+            })
+            .filter(|s| self.matches(s))
+    }
+}
+
+fn allows<J: PartialEq>(slot: &Option<Vec<J>>, jamo: &J) -> bool {
+    slot.as_ref().is_none_or(|s| s.contains(jamo))
+}
+
+/// ## Pattern
+/// Syllables to look for, written as jamo slots:
+/// - `?` is any jamo, e.g. `?ㅏㄹ` for any initial, `ㅏ` and `ㄹ`
+/// - `*` is anything for the rest of the syllable, e.g. `ㄱ*`
+/// - `[ㅓㅗ]` is either jamo
+///
+/// Syllables may be typed whole, `가*` is `ㄱㅏ*`. A syllable starting
+/// with its vowel has any initial, one written without a final has none.
+/// As when typing Hangul, a consonant after a vowel is a final unless a
+/// vowel follows it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern(Vec<SyllablePattern>);
+impl Pattern {
+    /// The pattern of a single syllable
+    pub fn syllable(&self) -> Option<&SyllablePattern> {
+        match self.0.as_slice() {
+            [s] => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Whether the syllables of `hangul` match the pattern one to one
+    pub fn matches(&self, hangul: &Hangul) -> bool {
+        hangul.len() == self.0.len() && self.find(hangul) == Some(0)
+    }
+
+    /// Position of the first run of syllables in `hangul` matching
+    pub fn find(&self, hangul: &Hangul) -> Option<usize> {
+        hangul.windows(self.0.len()).position(|w| {
+            w.iter().zip(self.0.iter()).all(|(s, p)| p.matches(s))
+        })
+    }
+}
+impl<'a> TryFrom<&'a str> for Pattern {
+    type Error = PatternError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let tokens = tokens(value)?;
+        let mut tokens = tokens.iter();
+        let mut ret = vec![];
+        while let Some(token) = tokens.next() {
+            // Initial
+            let initial = match token {
+                Token::Rest => {
+                    ret.push(SyllablePattern {
+                        initial: None,
+                        medial: None,
+                        finale: None,
+                    });
+                    continue;
+                }
+                Token::Any => None,
+                Token::Jamo(j) if is_medial(j) => None,
+                Token::Jamo(j) => Some(slot(j)?),
+            };
+            // Medial
+            let token = match token {
+                Token::Jamo(j) if is_medial(j) => token,
+                _ => tokens.next().ok_or(PatternError::MissingVowel)?,
+            };
+            let medial = match token {
+                Token::Rest => {
+                    ret.push(SyllablePattern {
+                        initial,
+                        medial: None,
+                        finale: None,
+                    });
+                    continue;
+                }
+                Token::Any => None,
+                Token::Jamo(j) if is_medial(j) => Some(slot(j)?),
+                Token::Jamo(_) => return Err(PatternError::MissingVowel),
+            };
+            // Final, unless it starts the next syllable
+            let mut ahead = tokens.clone();
+            let finale = match (ahead.next(), ahead.next()) {
+                (Some(Token::Rest), _) => {
+                    tokens.next();
+                    None
+                }
+                (Some(Token::Jamo(j)), _) if is_medial(j) => Some(vec![None]),
+                (_, Some(Token::Jamo(j))) if is_medial(j) => Some(vec![None]),
+                (Some(Token::Any), _) => {
+                    tokens.next();
+                    Some(FinalJamo::all().into_iter().map(Some).collect())
+                }
+                (Some(Token::Jamo(j)), _) => {
+                    tokens.next();
+                    Some(slot::<FinalJamo>(j)?.into_iter().map(Some).collect())
+                }
+                (None, _) => Some(vec![None]),
+            };
+            ret.push(SyllablePattern {
+                initial,
+                medial,
+                finale,
+            });
+        }
+        match ret.is_empty() {
+            true => Err(PatternError::Empty),
+            false => Ok(Self(ret)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// `?`
+    Any,
+    /// `*`
+    Rest,
+    /// A jamo or a `[...]` of them
+    Jamo(Vec<Jamo>),
+}
+
+fn tokens(pattern: &str) -> PatternResult<Vec<Token>> {
+    let jamo =
+        |c: char| Jamo::try_from(c).map_err(|_| PatternError::InvalidChar(c));
+    let mut ret = vec![];
+    let mut chars = pattern.chars().filter(|c| !c.is_whitespace());
+    while let Some(c) = chars.next() {
+        match c {
+            '?' => ret.push(Token::Any),
+            '*' => ret.push(Token::Rest),
+            '[' => {
+                let mut set = vec![];
+                loop {
+                    match chars.next() {
+                        Some(']') if set.is_empty() => {
+                            return Err(PatternError::InvalidChar(']'));
+                        }
+                        Some(']') => break,
+                        Some(c) => set.push(jamo(c)?),
+                        None => return Err(PatternError::Unclosed),
+                    }
+                }
+                ret.push(Token::Jamo(set));
+            }
+            c => match Syllable::try_from(c) {
+                Ok(s) => ret
+                    .extend(s.jamo().into_iter().map(|j| Token::Jamo(vec![j]))),
+                Err(_) => ret.push(Token::Jamo(vec![jamo(c)?])),
+            },
+        }
+    }
+    Ok(ret)
+}
+
+fn is_medial(jamo: &[Jamo]) -> bool {
+    jamo.iter().all(|j| MedialJamo::try_from(*j).is_ok())
+}
+
+fn slot<J: TryFrom<Jamo>>(jamo: &[Jamo]) -> PatternResult<Vec<J>> {
+    jamo.iter()
+        .map(|j| {
+            J::try_from(*j).map_err(|_| PatternError::Misplaced(char::from(j)))
+        })
+        .collect()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PatternError {
+    #[error("Pattern is empty")]
+    Empty,
+    #[error("Can not use {0} in a pattern")]
+    InvalidChar(char),
+    #[error("Missing ] after [")]
+    Unclosed,
+    #[error("Expected a vowel after the initial")]
+    MissingVowel,
+    #[error("{0} can not stand where it is")]
+    Misplaced(char),
+}
+pub type PatternResult<T> = Result<T, PatternError>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{hangul::Hangul, pattern::Pattern};
+
+    fn find(pattern: &str, word: &str) -> Option<usize> {
+        Pattern::try_from(pattern)
+            .unwrap()
+            .find(&Hangul::try_from(word).unwrap())
+    }
+
+    fn count(pattern: &str) -> usize {
+        Pattern::try_from(pattern)
+            .unwrap()
+            .syllable()
+            .unwrap()
+            .syllables()
+            .count()
+    }
+
+    #[test]
+    fn test_pattern() {
+        assert_eq!(find("?ㅏㄹ", "오늘"), None);
+        assert_eq!(find("?ㅏㄹ", "하늘날"), Some(2));
+        assert_eq!(find("ㄱ*", "학교"), Some(1));
+        assert_eq!(find("[ㅓㅗ]", "사과"), None);
+        assert_eq!(find("ㅅ[ㅓㅗ]", "도서관"), Some(1));
+        assert_eq!(find("가?ㅏ", "가나"), Some(0));
+        assert_eq!(find("가나", "간아"), None);
+        assert_eq!(find("학*", "학교"), Some(0));
+        assert_eq!(find("?ㅏ?", "한국"), Some(0));
+        assert_eq!(find("?ㅏ?", "가게"), None);
+        assert_eq!(count("*"), 11172);
+        assert_eq!(count("ㄱ*"), 588);
+        assert_eq!(count("?ㅏㄹ"), 19);
+        assert_eq!(count("[ㅓㅗ]"), 38);
+        assert!(Pattern::try_from("").is_err());
+        assert!(Pattern::try_from("ㄱ").is_err());
+        assert!(Pattern::try_from("학?").is_err());
+        assert!(Pattern::try_from("[ㅏ").is_err());
+        assert!(Pattern::try_from("house").is_err());
+    }
+}
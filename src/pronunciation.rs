@@ -0,0 +1,256 @@
+use crate::{
+    hangul::Hangul,
+    jamo::{FinalJamo, InitialJamo, MedialJamo},
+};
+
+/// ## Pronounce
+/// Spoken form of `hangul`, e.g. 한국어 is pronounced [한구거], 신라
+/// [실라] and 같이 [가치]. Sound changes are applied at every syllable
+/// boundary:
+/// - Liaison (연음), a final moves over to a following `ㅇ` initial
+/// - Palatalization (구개음화), `ㄷ ㅌ` become `ㅈ ㅊ` before `ㅣ`
+/// - Aspiration, `ㅎ` merges with a neighbouring `ㄱ ㄷ ㅂ ㅈ`
+/// - Nasalization, `ㄱ ㄷ ㅂ` before `ㄴ ㅁ` and `ㄹ` after other
+///   consonants than `ㄴ ㄹ`
+/// - Lateralization, `ㄴ` next to `ㄹ`
+/// - Tensification (경음화), `ㄱ ㄷ ㅂ ㅅ ㅈ` after a stop
+///
+/// Remaining finals are reduced to one of the seven representative
+/// codas `ㄱ ㄴ ㄷ ㄹ ㅁ ㅂ ㅇ`.
+pub fn pronounce(hangul: &Hangul) -> Hangul {
+    apply(hangul, true)
+}
+
+/// Same as `pronounce` but without tensification, which is not
+/// reflected by any of the romanizations
+pub(crate) fn assimilate(hangul: &Hangul) -> Hangul {
+    apply(hangul, false)
+}
+
+fn apply(hangul: &Hangul, tensify: bool) -> Hangul {
+    let mut ret = hangul.clone();
+    for i in 0..ret.len() {
+        let finale = match ret[i].finale() {
+            Some(f) => f,
+            None => continue,
+        };
+        let next = ret
+            .get(i + 1)
+            .and_then(|s| Some((s.initial()?, s.medial()?)));
+        let (finale, initial) = match next {
+            Some((ij, mj)) => boundary(finale, ij, mj, tensify),
+            None => (Some(neutralize(finale)), None),
+        };
+        ret[i].set_finale(finale);
+        if let Some(ij) = initial {
+            ret[i + 1].set_initial(ij);
+        }
+    }
+    ret
+}
+
+fn boundary(
+    finale: FinalJamo,
+    initial: InitialJamo,
+    medial: MedialJamo,
+    tensify: bool,
+) -> (Option<FinalJamo>, Option<InitialJamo>) {
+    match initial {
+        InitialJamo::Ng => return liaison(finale, medial),
+        InitialJamo::H => return aspirate_initial(finale, medial),
+        _ => (),
+    }
+    if let Some(ret) = aspirate_finale(finale, initial, tensify) {
+        return ret;
+    }
+    let tense = tensify && is_stop(finale);
+    let finale = neutralize(finale);
+    let (finale, initial) = match (finale, initial) {
+        (FinalJamo::N, InitialJamo::R) | (FinalJamo::R, InitialJamo::N) => {
+            (FinalJamo::R, InitialJamo::R)
+        }
+        (f, InitialJamo::R) if f != FinalJamo::R => (nasal(f), InitialJamo::N),
+        (f, i @ (InitialJamo::N | InitialJamo::M)) => (nasal(f), i),
+        (f, i) if tense => (f, tense_of(i)),
+        (f, i) => (f, i),
+    };
+    (Some(finale), Some(initial))
+}
+
+fn liaison(
+    finale: FinalJamo,
+    medial: MedialJamo,
+) -> (Option<FinalJamo>, Option<InitialJamo>) {
+    let (finale, initial) = match finale {
+        FinalJamo::Ng => (Some(FinalJamo::Ng), None),
+        // ㅎ is silent before a vowel
+        FinalJamo::H => (None, None),
+        FinalJamo::Nch => (None, Some(InitialJamo::N)),
+        FinalJamo::Lh => (None, Some(InitialJamo::R)),
+        f => match f.components() {
+            (f1, Some(f2)) => (Some(f1), InitialJamo::try_from(f2).ok()),
+            (f1, None) => (None, InitialJamo::try_from(f1).ok()),
+        },
+    };
+    let initial = match (initial, medial) {
+        (Some(InitialJamo::D), MedialJamo::I) => Some(InitialJamo::J),
+        (Some(InitialJamo::T), MedialJamo::I) => Some(InitialJamo::Ch),
+        (i, _) => i,
+    };
+    (finale, initial)
+}
+
+/// `ㅎ` initial after a stop, e.g. 입학 [이팍] and 닫히다 [다치다]
+fn aspirate_initial(
+    finale: FinalJamo,
+    medial: MedialJamo,
+) -> (Option<FinalJamo>, Option<InitialJamo>) {
+    let (rest, stop) = match finale.components() {
+        (f1, Some(f2 @ (FinalJamo::G | FinalJamo::B | FinalJamo::J))) => {
+            (Some(f1), f2)
+        }
+        _ => (None, finale),
+    };
+    let initial = match stop {
+        FinalJamo::G | FinalJamo::Gg | FinalJamo::Gs | FinalJamo::K => {
+            InitialJamo::K
+        }
+        FinalJamo::B | FinalJamo::Bs | FinalJamo::P => InitialJamo::P,
+        FinalJamo::J | FinalJamo::Ch => InitialJamo::Ch,
+        FinalJamo::D | FinalJamo::S | FinalJamo::Ss | FinalJamo::T
+            if medial == MedialJamo::I =>
+        {
+            InitialJamo::Ch
+        }
+        FinalJamo::D | FinalJamo::S | FinalJamo::Ss | FinalJamo::T => {
+            InitialJamo::T
+        }
+        _ => return (Some(neutralize(finale)), Some(InitialJamo::H)),
+    };
+    (rest, Some(initial))
+}
+
+/// `ㅎ` final before a consonant, e.g. 좋고 [조코] and 놓는 [논는]
+fn aspirate_finale(
+    finale: FinalJamo,
+    initial: InitialJamo,
+    tensify: bool,
+) -> Option<(Option<FinalJamo>, Option<InitialJamo>)> {
+    let rest = match finale {
+        FinalJamo::H => None,
+        FinalJamo::Nch => Some(FinalJamo::N),
+        FinalJamo::Lh => Some(FinalJamo::R),
+        _ => return None,
+    };
+    let initial = match initial {
+        InitialJamo::G => InitialJamo::K,
+        InitialJamo::D => InitialJamo::T,
+        InitialJamo::J => InitialJamo::Ch,
+        InitialJamo::S if tensify => InitialJamo::Ss,
+        InitialJamo::S => InitialJamo::S,
+        InitialJamo::N if rest == Some(FinalJamo::R) => {
+            return Some((rest, Some(InitialJamo::R)));
+        }
+        InitialJamo::N => {
+            return Some((Some(FinalJamo::N), Some(InitialJamo::N)));
+        }
+        _ => return None,
+    };
+    Some((rest, Some(initial)))
+}
+
+/// Whether `finale` makes a following plain consonant tense, this
+/// includes `ㄵ ㄻ` which only occur in verb stems
+fn is_stop(finale: FinalJamo) -> bool {
+    matches!(
+        neutralize(finale),
+        FinalJamo::G | FinalJamo::D | FinalJamo::B
+    ) || matches!(
+        finale,
+        FinalJamo::Nc | FinalJamo::Lm | FinalJamo::Lb | FinalJamo::Lt
+    )
+}
+
+fn tense_of(initial: InitialJamo) -> InitialJamo {
+    match initial {
+        InitialJamo::G => InitialJamo::Gg,
+        InitialJamo::D => InitialJamo::Dd,
+        InitialJamo::B => InitialJamo::Bb,
+        InitialJamo::S => InitialJamo::Ss,
+        InitialJamo::J => InitialJamo::Jj,
+        i => i,
+    }
+}
+
+/// Reduces `finale` to its representative coda
+pub(crate) fn neutralize(finale: FinalJamo) -> FinalJamo {
+    match finale {
+        FinalJamo::G
+        | FinalJamo::Gg
+        | FinalJamo::Gs
+        | FinalJamo::Lg
+        | FinalJamo::K => FinalJamo::G,
+        FinalJamo::N | FinalJamo::Nc | FinalJamo::Nch => FinalJamo::N,
+        FinalJamo::D
+        | FinalJamo::S
+        | FinalJamo::Ss
+        | FinalJamo::J
+        | FinalJamo::Ch
+        | FinalJamo::T
+        | FinalJamo::H => FinalJamo::D,
+        FinalJamo::R
+        | FinalJamo::Lb
+        | FinalJamo::Ls
+        | FinalJamo::Lt
+        | FinalJamo::Lh => FinalJamo::R,
+        FinalJamo::M | FinalJamo::Lm => FinalJamo::M,
+        FinalJamo::B | FinalJamo::Bs | FinalJamo::P | FinalJamo::Lph => {
+            FinalJamo::B
+        }
+        FinalJamo::Ng => FinalJamo::Ng,
+    }
+}
+
+fn nasal(finale: FinalJamo) -> FinalJamo {
+    match finale {
+        FinalJamo::G => FinalJamo::Ng,
+        FinalJamo::D => FinalJamo::N,
+        FinalJamo::B => FinalJamo::M,
+        f => f,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hangul::Hangul, pronunciation::pronounce};
+
+    fn pronounced(s: &str) -> String {
+        pronounce(&Hangul::try_from(s).unwrap()).to_string()
+    }
+
+    #[test]
+    fn test_pronounce() {
+        // Liaison
+        assert_eq!(pronounced("한국어"), "한구거");
+        assert_eq!(pronounced("읽어"), "일거");
+        assert_eq!(pronounced("좋아"), "조아");
+        // Nasalization and lateralization
+        assert_eq!(pronounced("먹는"), "멍는");
+        assert_eq!(pronounced("종로"), "종노");
+        assert_eq!(pronounced("신라"), "실라");
+        // Tensification
+        assert_eq!(pronounced("국밥"), "국빱");
+        assert_eq!(pronounced("학교"), "학꾜");
+        assert_eq!(pronounced("넓게"), "널께");
+        // Palatalization
+        assert_eq!(pronounced("같이"), "가치");
+        assert_eq!(pronounced("해돋이"), "해도지");
+        assert_eq!(pronounced("닫히다"), "다치다");
+        // Aspiration
+        assert_eq!(pronounced("좋고"), "조코");
+        assert_eq!(pronounced("입학"), "이팍");
+        assert_eq!(pronounced("밝히다"), "발키다");
+        assert_eq!(pronounced("놓는"), "논는");
+        assert_eq!(pronounced("닭"), "닥");
+    }
+}
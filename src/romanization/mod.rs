@@ -0,0 +1,74 @@
+mod mr;
+mod rr;
+mod yale;
+
+pub use mr::mr;
+pub use rr::rr;
+pub use yale::yale;
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{hangul::Hangul, jamo::Jamo};
+
+/// ## Scheme
+/// Romanization used both when reading input and when
+/// displaying Hangul
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Scheme {
+    /// Revised Romanization
+    #[default]
+    Rr,
+    /// McCune–Reischauer
+    Mr,
+    /// Yale, jamo by jamo without sound changes
+    Yale,
+}
+impl Scheme {
+    pub fn name(self) -> &'static str {
+        match self {
+            Scheme::Rr => "RR",
+            Scheme::Mr => "MR",
+            Scheme::Yale => "Yale",
+        }
+    }
+
+    pub fn romanize(self, hangul: &Hangul) -> String {
+        match self {
+            Scheme::Rr => rr(hangul),
+            Scheme::Mr => mr(hangul),
+            Scheme::Yale => yale(hangul),
+        }
+    }
+
+    /// How `jamo` is typed in this scheme
+    pub fn spelling(self, jamo: Jamo) -> String {
+        match self {
+            Scheme::Rr => jamo.rr(),
+            Scheme::Mr => mr::spelling(jamo),
+            Scheme::Yale => yale::spelling(jamo),
+        }
+    }
+}
+impl FromStr for Scheme {
+    type Err = RomanizationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "rr" => Ok(Scheme::Rr),
+            "mr" => Ok(Scheme::Mr),
+            "yale" => Ok(Scheme::Yale),
+            _ => Err(RomanizationError::UnknownScheme(s.into())),
+        }
+    }
+}
+impl Display for Scheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RomanizationError {
+    #[error("Unknown romanization scheme: {0}")]
+    UnknownScheme(String),
+}
@@ -0,0 +1,184 @@
+use crate::{
+    hangul::Hangul,
+    jamo::{FinalJamo, InitialJamo, Jamo, MedialJamo},
+    pronunciation::assimilate,
+};
+
+/// ## McCune–Reischauer
+/// Romanizes `hangul` as it is pronounced. Plain stops are voiced
+/// between voiced sounds, e.g. 부산 becomes `pusan` while 한국 becomes
+/// `han'guk`.
+pub fn mr(hangul: &Hangul) -> String {
+    let mut ret = String::new();
+    // `None` at the start of the word
+    let mut previous: Option<(MedialJamo, Option<FinalJamo>)> = None;
+    for syl in assimilate(hangul).iter() {
+        let (ij, mj) = match (syl.initial(), syl.medial()) {
+            (Some(ij), Some(mj)) => (ij, mj),
+            // Incomplete syllable, nothing to pronounce
+            (Some(ij), None) => {
+                ret.push_str(&spelling(ij.into()));
+                previous = None;
+                continue;
+            }
+            (None, _) => continue,
+        };
+        let voiced = match previous {
+            None => false,
+            Some((_, None)) => true,
+            Some((_, Some(fj))) => matches!(
+                fj,
+                FinalJamo::N | FinalJamo::R | FinalJamo::M | FinalJamo::Ng
+            ),
+        };
+        match (previous, ij) {
+            (Some((_, Some(FinalJamo::N))), InitialJamo::G) => {
+                ret.push_str("'g")
+            }
+            (Some((_, Some(FinalJamo::R))), InitialJamo::R) => ret.push('l'),
+            (Some((pmj, None)), InitialJamo::Ng)
+                if separate_vowels(pmj, mj) =>
+            {
+                ret.push('\'')
+            }
+            _ => ret.push_str(initial(ij, mj, voiced)),
+        }
+        ret.push_str(medial(mj));
+        if let Some(fj) = syl.finale() {
+            ret.push_str(finale(fj));
+        }
+        previous = Some((mj, syl.finale()));
+    }
+    ret
+}
+
+/// Input spelling of `jamo`
+pub fn spelling(jamo: Jamo) -> String {
+    match jamo {
+        Jamo::G => "k".into(),
+        Jamo::Gg => "kk".into(),
+        Jamo::D => "t".into(),
+        Jamo::Dd => "tt".into(),
+        Jamo::B => "p".into(),
+        Jamo::Bb => "pp".into(),
+        Jamo::J => "ch".into(),
+        Jamo::Jj => "tch".into(),
+        Jamo::Ch => "ch'".into(),
+        Jamo::K => "k'".into(),
+        Jamo::T => "t'".into(),
+        Jamo::P => "p'".into(),
+        Jamo::Eo => "ŏ".into(),
+        Jamo::Yeo => "yŏ".into(),
+        Jamo::Wo => "wŏ".into(),
+        Jamo::Eu => "ŭ".into(),
+        Jamo::Ui => "ŭi".into(),
+        j => j.rr(),
+    }
+}
+
+fn initial(
+    jamo: InitialJamo,
+    medial: MedialJamo,
+    voiced: bool,
+) -> &'static str {
+    match (jamo, voiced) {
+        (InitialJamo::G, false) => "k",
+        (InitialJamo::G, true) => "g",
+        (InitialJamo::Gg, _) => "kk",
+        (InitialJamo::N, _) => "n",
+        (InitialJamo::D, false) => "t",
+        (InitialJamo::D, true) => "d",
+        (InitialJamo::Dd, _) => "tt",
+        (InitialJamo::R, _) => "r",
+        (InitialJamo::M, _) => "m",
+        (InitialJamo::B, false) => "p",
+        (InitialJamo::B, true) => "b",
+        (InitialJamo::Bb, _) => "pp",
+        (InitialJamo::S, _)
+            if matches!(medial, MedialJamo::I | MedialJamo::Wi) =>
+        {
+            "sh"
+        }
+        (InitialJamo::S, _) => "s",
+        (InitialJamo::Ss, _) => "ss",
+        (InitialJamo::Ng, _) => "",
+        (InitialJamo::J, false) => "ch",
+        (InitialJamo::J, true) => "j",
+        (InitialJamo::Jj, _) => "tch",
+        (InitialJamo::Ch, _) => "ch'",
+        (InitialJamo::K, _) => "k'",
+        (InitialJamo::T, _) => "t'",
+        (InitialJamo::P, _) => "p'",
+        (InitialJamo::H, _) => "h",
+    }
+}
+
+fn medial(jamo: MedialJamo) -> &'static str {
+    match jamo {
+        MedialJamo::A => "a",
+        MedialJamo::Ae => "ae",
+        MedialJamo::Ya => "ya",
+        MedialJamo::Yae => "yae",
+        MedialJamo::Eo => "ŏ",
+        MedialJamo::E => "e",
+        MedialJamo::Yeo => "yŏ",
+        MedialJamo::Ye => "ye",
+        MedialJamo::O => "o",
+        MedialJamo::Wa => "wa",
+        MedialJamo::Wae => "wae",
+        MedialJamo::Oe => "oe",
+        MedialJamo::Yo => "yo",
+        MedialJamo::U => "u",
+        MedialJamo::Wo => "wŏ",
+        MedialJamo::We => "we",
+        MedialJamo::Wi => "wi",
+        MedialJamo::Yu => "yu",
+        MedialJamo::Eu => "ŭ",
+        MedialJamo::Ui => "ŭi",
+        MedialJamo::I => "i",
+    }
+}
+
+/// Expects a representative coda, see `pronunciation::neutralize`
+fn finale(jamo: FinalJamo) -> &'static str {
+    match jamo {
+        FinalJamo::G => "k",
+        FinalJamo::N => "n",
+        FinalJamo::D => "t",
+        FinalJamo::R => "l",
+        FinalJamo::M => "m",
+        FinalJamo::B => "p",
+        FinalJamo::Ng => "ng",
+        _ => "",
+    }
+}
+
+/// Whether two vowels in a row would read as a single digraph
+fn separate_vowels(previous: MedialJamo, next: MedialJamo) -> bool {
+    let (prev, next) = (medial(previous), medial(next));
+    matches!(
+        (prev.chars().last(), next.chars().next()),
+        (Some('a' | 'o'), Some('e')) | (Some('ŭ' | 'u'), Some('i'))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hangul::Hangul, romanization::mr};
+
+    fn mr_of(s: &str) -> String {
+        mr(&Hangul::try_from(s).unwrap())
+    }
+
+    #[test]
+    fn test_mr() {
+        assert_eq!(mr_of("부산"), "pusan");
+        assert_eq!(mr_of("한국"), "han'guk");
+        assert_eq!(mr_of("서울"), "sŏul");
+        assert_eq!(mr_of("평양"), "p'yŏngyang");
+        assert_eq!(mr_of("신라"), "shilla");
+        assert_eq!(mr_of("독립문"), "tongnimmun");
+        assert_eq!(mr_of("김치"), "kimch'i");
+        assert_eq!(mr_of("대구"), "taegu");
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    hangul::Hangul,
+    jamo::{FinalJamo, InitialJamo, Jamo, MedialJamo},
+    pronunciation::assimilate,
+};
+
+/// ## Revised Romanization
+/// Romanizes `hangul` as it is pronounced, e.g. 한국어 becomes
+/// `hangugeo` and 신라 becomes `silla`.
+pub fn rr(hangul: &Hangul) -> String {
+    let mut ret = String::new();
+    let mut previous = None;
+    for syl in assimilate(hangul).iter() {
+        match (syl.initial(), syl.medial()) {
+            (Some(InitialJamo::R), Some(_))
+                if previous == Some(FinalJamo::R) =>
+            {
+                ret.push('l')
+            }
+            (Some(ij), Some(_)) => ret.push_str(initial(ij)),
+            // Incomplete syllable, nothing to pronounce
+            (Some(ij), None) => ret.push_str(&Jamo::from(ij).rr()),
+            (None, _) => (),
+        }
+        if let Some(mj) = syl.medial() {
+            ret.push_str(&medial(mj));
+        }
+        if let Some(fj) = syl.finale() {
+            ret.push_str(finale(fj));
+        }
+        previous = syl.finale();
+    }
+    ret
+}
+
+fn initial(jamo: InitialJamo) -> &'static str {
+    match jamo {
+        InitialJamo::G => "g",
+        InitialJamo::Gg => "kk",
+        InitialJamo::N => "n",
+        InitialJamo::D => "d",
+        InitialJamo::Dd => "tt",
+        InitialJamo::R => "r",
+        InitialJamo::M => "m",
+        InitialJamo::B => "b",
+        InitialJamo::Bb => "pp",
+        InitialJamo::S => "s",
+        InitialJamo::Ss => "ss",
+        InitialJamo::Ng => "",
+        InitialJamo::J => "j",
+        InitialJamo::Jj => "jj",
+        InitialJamo::Ch => "ch",
+        InitialJamo::K => "k",
+        InitialJamo::T => "t",
+        InitialJamo::P => "p",
+        InitialJamo::H => "h",
+    }
+}
+
+fn medial(jamo: MedialJamo) -> String {
+    Jamo::from(jamo).rr()
+}
+
+/// Expects a representative coda, see `pronunciation::neutralize`
+fn finale(jamo: FinalJamo) -> &'static str {
+    match jamo {
+        FinalJamo::G => "k",
+        FinalJamo::N => "n",
+        FinalJamo::D => "t",
+        FinalJamo::R => "l",
+        FinalJamo::M => "m",
+        FinalJamo::B => "p",
+        FinalJamo::Ng => "ng",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hangul::Hangul, romanization::rr};
+
+    fn rr_of(s: &str) -> String {
+        rr(&Hangul::try_from(s).unwrap())
+    }
+
+    #[test]
+    fn test_rr_initial_and_final() {
+        assert_eq!(rr_of("가곡"), "gagok");
+        assert_eq!(rr_of("닭"), "dak");
+        assert_eq!(rr_of("밭"), "bat");
+        assert_eq!(rr_of("라디오"), "radio");
+        assert_eq!(rr_of("물"), "mul");
+    }
+
+    #[test]
+    fn test_rr_sound_changes() {
+        // Liaison
+        assert_eq!(rr_of("한국어"), "hangugeo");
+        assert_eq!(rr_of("읽어"), "ilgeo");
+        assert_eq!(rr_of("좋아"), "joa");
+        // Nasalization
+        assert_eq!(rr_of("백마"), "baengma");
+        assert_eq!(rr_of("종로"), "jongno");
+        assert_eq!(rr_of("독립"), "dongnip");
+        assert_eq!(rr_of("합니다"), "hamnida");
+        // Lateralization
+        assert_eq!(rr_of("신라"), "silla");
+        assert_eq!(rr_of("설날"), "seollal");
+        assert_eq!(rr_of("한글"), "hangeul");
+        // Palatalization and aspiration
+        assert_eq!(rr_of("같이"), "gachi");
+        assert_eq!(rr_of("좋고"), "joko");
+    }
+}
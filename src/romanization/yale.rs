@@ -0,0 +1,160 @@
+use std::sync::OnceLock;
+
+use crate::{
+    hangul::Hangul,
+    hangul_parser::HangulParser,
+    jamo::{FinalJamo, InitialJamo, Jamo, MedialJamo},
+    romanization::Scheme,
+    syllable::Syllable,
+};
+
+/// ## Yale
+/// Transliterates `hangul` jamo by jamo, e.g. 한국어 becomes
+/// `hankwuke` and 가까 becomes `ka.kka`. Syllables are separated by `.`
+/// only where reading the text back would otherwise give different
+/// syllables, which makes the transliteration reversible with
+/// `HangulParser::parse`.
+pub fn yale(hangul: &Hangul) -> String {
+    let mut ret = String::new();
+    let mut previous: Option<(&Syllable, String)> = None;
+    for syl in hangul.iter() {
+        let current = transliterate(syl);
+        if let Some((prev, prev_str)) = previous
+            && !unambiguous(prev, syl, &prev_str, &current)
+        {
+            ret.push('.');
+        }
+        ret.push_str(&current);
+        previous = Some((syl, current));
+    }
+    ret
+}
+
+/// Input spelling of `jamo`
+pub fn spelling(jamo: Jamo) -> String {
+    if let Ok(mj) = MedialJamo::try_from(jamo) {
+        return medial(mj).into();
+    }
+    if let Ok(ij) = InitialJamo::try_from(jamo) {
+        return initial(ij).into();
+    }
+    match FinalJamo::try_from(jamo) {
+        Ok(fj) => finale(fj),
+        Err(_) => String::new(),
+    }
+}
+
+fn transliterate(syl: &Syllable) -> String {
+    let mut ret = String::new();
+    match (syl.initial(), syl.medial()) {
+        (Some(InitialJamo::Ng), Some(_)) => (),
+        (Some(ij), _) => ret.push_str(initial(ij)),
+        (None, _) => (),
+    }
+    if let Some(mj) = syl.medial() {
+        ret.push_str(medial(mj));
+    }
+    if let Some(fj) = syl.finale() {
+        ret.push_str(&finale(fj));
+    }
+    ret
+}
+
+fn unambiguous(
+    previous: &Syllable,
+    current: &Syllable,
+    previous_str: &str,
+    current_str: &str,
+) -> bool {
+    static PARSER: OnceLock<HangulParser> = OnceLock::new();
+    let parser = PARSER.get_or_init(|| HangulParser::new(Scheme::Yale));
+    let joined = format!("{}{}", previous_str, current_str);
+    parser.parse(&joined).as_slice() == [*previous, *current]
+}
+
+fn initial(jamo: InitialJamo) -> &'static str {
+    match jamo {
+        InitialJamo::G => "k",
+        InitialJamo::Gg => "kk",
+        InitialJamo::N => "n",
+        InitialJamo::D => "t",
+        InitialJamo::Dd => "tt",
+        InitialJamo::R => "l",
+        InitialJamo::M => "m",
+        InitialJamo::B => "p",
+        InitialJamo::Bb => "pp",
+        InitialJamo::S => "s",
+        InitialJamo::Ss => "ss",
+        InitialJamo::Ng => "ng",
+        InitialJamo::J => "c",
+        InitialJamo::Jj => "cc",
+        InitialJamo::Ch => "ch",
+        InitialJamo::K => "kh",
+        InitialJamo::T => "th",
+        InitialJamo::P => "ph",
+        InitialJamo::H => "h",
+    }
+}
+
+fn medial(jamo: MedialJamo) -> &'static str {
+    match jamo {
+        MedialJamo::A => "a",
+        MedialJamo::Ae => "ay",
+        MedialJamo::Ya => "ya",
+        MedialJamo::Yae => "yay",
+        MedialJamo::Eo => "e",
+        MedialJamo::E => "ey",
+        MedialJamo::Yeo => "ye",
+        MedialJamo::Ye => "yey",
+        MedialJamo::O => "o",
+        MedialJamo::Wa => "wa",
+        MedialJamo::Wae => "way",
+        MedialJamo::Oe => "oy",
+        MedialJamo::Yo => "yo",
+        MedialJamo::U => "wu",
+        MedialJamo::Wo => "we",
+        MedialJamo::We => "wey",
+        MedialJamo::Wi => "wi",
+        MedialJamo::Yu => "yu",
+        MedialJamo::Eu => "u",
+        MedialJamo::Ui => "uy",
+        MedialJamo::I => "i",
+    }
+}
+
+/// Compound finals are written as their components
+fn finale(jamo: FinalJamo) -> String {
+    match jamo.components() {
+        (f1, Some(f2)) => finale(f1) + &finale(f2),
+        (f1, None) => match InitialJamo::try_from(f1) {
+            Ok(ij) => initial(ij).into(),
+            Err(_) => String::new(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        hangul::Hangul,
+        hangul_parser::HangulParser,
+        romanization::{Scheme, yale},
+        syllable::Syllable,
+    };
+
+    #[test]
+    fn test_yale_round_trip() {
+        let parser = HangulParser::new(Scheme::Yale);
+        for c in '가'..='힣' {
+            let hangul = Hangul::from(Syllable::try_from(c).unwrap());
+            assert_eq!(parser.parse(&yale(&hangul)), hangul, "{}", c);
+        }
+        for word in ["한국어", "가까", "각하", "옷을", "오아", "닭이", "않하"]
+        {
+            let hangul = Hangul::try_from(word).unwrap();
+            assert_eq!(parser.parse(&yale(&hangul)), hangul, "{}", word);
+        }
+        assert_eq!(yale(&Hangul::try_from("한국어").unwrap()), "hankwuke");
+        assert_eq!(yale(&Hangul::try_from("가까").unwrap()), "ka.kka");
+    }
+}
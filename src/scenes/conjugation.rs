@@ -0,0 +1,41 @@
+use terminal::{
+    Scene, SceneType, TerminalResult,
+    code::TerminalCode,
+    elements::{Button, Dispatch, Rectangle, TextLine},
+};
+
+use crate::elements::{ConjugationInfo, Log};
+
+const WIDTH: u16 = 57;
+const HEIGHT: u16 = 21;
+const HEADING: &str = "Conjugation";
+const CLOSE: &str = "Close";
+
+/// Conjugation of the selected `log` entry
+pub fn conjugation_scene(log: Dispatch<Log>) -> TerminalResult<Scene> {
+    let mut scene = Scene::new(SceneType::PopUp(12, 5));
+    scene.insert_block(
+        "background".into(),
+        Rectangle::new((0, 0, 0), (WIDTH, HEIGHT), true),
+    )?;
+    scene.insert_block(
+        "heading".into(),
+        TextLine::default()
+            .with_pos((WIDTH - HEADING.len() as u16) / 2, 1)
+            .with_width(HEADING.len() as u16)
+            .with_value(HEADING.into())
+            .clone(),
+    )?;
+    scene.insert_block(
+        "conjugation".into(),
+        ConjugationInfo::new((3, 3, 0), log),
+    )?;
+    scene.insert_input(Button::new(
+        ((WIDTH - 11) / 2, HEIGHT - 3, 0),
+        CLOSE.into(),
+        11,
+        3,
+        Some(|| TerminalCode::PreviousScene),
+    ));
+    Ok(scene)
+}
@@ -0,0 +1,29 @@
+use terminal::{
+    Scene, SceneType, TerminalResult,
+    elements::{Dispatch, Rectangle, TextLine},
+};
+
+use crate::elements::{EntryDetails, Log};
+
+const WIDTH: u16 = 57;
+const HEIGHT: u16 = 21;
+const HEADING: &str = "Entry details";
+
+/// Part of speech, tags, example and notes of the selected `log` entry
+pub fn entry_details_scene(log: Dispatch<Log>) -> TerminalResult<Scene> {
+    let mut scene = Scene::new(SceneType::PopUp(12, 5));
+    scene.insert_block(
+        "background".into(),
+        Rectangle::new((0, 0, 0), (WIDTH, HEIGHT), true),
+    )?;
+    scene.insert_block(
+        "heading".into(),
+        TextLine::default()
+            .with_pos((WIDTH - HEADING.len() as u16) / 2, 1)
+            .with_width(HEADING.len() as u16)
+            .with_value(HEADING.into())
+            .clone(),
+    )?;
+    scene.insert_input(EntryDetails::new((3, 3, 0), WIDTH - 6, log));
+    Ok(scene)
+}
@@ -12,7 +12,8 @@ pub fn error_popup_scene(
 ) -> TerminalResult<Scene> {
     // border + button + heading + msg_height + potential margin
     let height =
-        (2 + 2 + 1 + error_msg.len() + usize::from(error_msg.len() > 0)) as u16;
+        (2 + 2 + 1 + error_msg.len() + usize::from(!error_msg.is_empty()))
+            as u16;
     let width = 11.max(
         Some(heading.len())
             .into_iter()
@@ -54,7 +55,7 @@ pub fn error_popup_scene(
 
     let bx = (width / 2).saturating_sub(9 / 2);
     let button = Button::new(
-        (bx, height as u16 - 2, 0),
+        (bx, height - 2, 0),
         "Close".into(),
         9,
         2,
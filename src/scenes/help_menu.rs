@@ -47,12 +47,70 @@ pub fn help_menu_scene() -> TerminalResult<Scene> {
         "submit-rr".into(),
         TextLine::default()
             .with_pos(1, 9)
+            .with_width(" - Enter . . . . . Submit romanized word".len() as u16)
+            .with_value(" - Enter . . . . . Submit romanized word".into())
+            .clone(),
+    )?;
+    scene.insert_block(
+        "space-rr".into(),
+        TextLine::default()
+            .with_pos(1, 11)
+            .with_width(
+                " - Space . . . . . Submit word and add a space".len() as u16
+            )
+            .with_value(" - Space . . . . . Submit word and add a space".into())
+            .clone(),
+    )?;
+    scene.insert_block(
+        "literal-rr".into(),
+        TextLine::default()
+            .with_pos(1, 13)
+            .with_width(
+                " - \" + Enter . . . Submit the rest verbatim, e.g. \"K-pop"
+                    .len() as u16,
+            )
+            .with_value(
+                " - \" + Enter . . . Submit the rest verbatim, e.g. \"K-pop"
+                    .into(),
+            )
+            .clone(),
+    )?;
+    scene.insert_block(
+        "mode-rr".into(),
+        TextLine::default()
+            .with_pos(1, 15)
+            .with_width(
+                " - Ctrl + k  . . . Switch romanization/keyboard layout".len()
+                    as u16,
+            )
+            .with_value(
+                " - Ctrl + k  . . . Switch romanization/keyboard layout".into(),
+            )
+            .clone(),
+    )?;
+    scene.insert_block(
+        "reading-rr".into(),
+        TextLine::default()
+            .with_pos(1, 17)
+            .with_width(
+                " - Up / Down . . . Choose reading of an ambiguous word".len()
+                    as u16,
+            )
+            .with_value(
+                " - Up / Down . . . Choose reading of an ambiguous word".into(),
+            )
+            .clone(),
+    )?;
+    scene.insert_block(
+        "hanja-rr".into(),
+        TextLine::default()
+            .with_pos(1, 19)
             .with_width(
-                " - Enter . . . . . Submit RR characters as syllable".len()
+                " - Hanja . . . . . Common Hanja are read as Hangul".len()
                     as u16,
             )
             .with_value(
-                " - Enter . . . . . Submit RR characters as syllable".into(),
+                " - Hanja . . . . . Common Hanja are read as Hangul".into(),
             )
             .clone(),
     )?;
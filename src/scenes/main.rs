@@ -1,14 +1,26 @@
+use std::path::PathBuf;
+
 use terminal::{
-    Scene, TerminalResult,
+    Scene, SceneType, TerminalResult,
     code::TerminalCode,
-    elements::{Button, Dispatch, LineHorizontal, LineVertical, TextLine},
+    elements::{
+        Button, Dispatch, LineHorizontal, LineVertical, Rectangle, TextLine,
+    },
+    traits::Input,
 };
 
 use crate::{
+    conjugation::Lemma,
     elements::{
-        DescriptionInput, HangulResult, JamoInfo, Log, PossibleInfo, RrInput,
+        DescriptionInput, HangulResult, JamoInfo, LemmaChoice, Log, LogSearch,
+        ModeInfo, PossibleInfo, RrInput,
     },
-    scenes::error_popup_scene,
+    hangul_parser::HangulParser,
+    keyboard::Layout,
+    scenes::{
+        SubScenes, conjugation_scene, entry_details_scene, error_popup_scene,
+    },
+    text::Text,
 };
 
 /* All syllables take 2 columns
@@ -18,19 +30,19 @@ use crate::{
 01     ------ Info ------                  │       ----  LOG ----
 02 +―――――+――――――――info-bot―――――――――――――――――+
 03       │  --------Hangul-------          │
-04 +―――――+―――――――hangul-bot――――――――――――――――+
-05  rr   │                                 │
-06 +―――――+―――――――――rr-bot――――――――――――――――――+
-07  Desc │                                 │
-08 +―――――+―――――――――desc-bot――――――――――――――――+
-09                 SAVE                    │
-10 +―――――――――――――――save-bot――――――――――――――――+
-11  Combinations:                          │
-12                                         │
+04       │  ------Pronunciation------      │
+05 +―――――+―――――――hangul-bot――――――――――――――――+
+06  rr   │                                 │
+07 +―――――+―――――――――rr-bot――――――――――――――――――+
+08  Desc │                                 │
+09 +―――――+―――――――――desc-bot――――――――――――――――+
+10                 SAVE                    │
+11 +―――――――――――――――save-bot――――――――――――――――+
+12  Combinations:                          │
 13                                         │
-14 +―――――――――――――――comb-bot――――――――――――――――+
-15     -------- Jamo Index -----------     │
-16                                         │
+14                                         │
+15 +―――――――――――――――comb-bot――――――――――――――――+
+16     -------- Jamo Index -----------     │
 17                                         │
 18                                         │
 19                                         │
@@ -44,7 +56,7 @@ use crate::{
 27                                         │
 28                                         │
 29                                         │
-30                                         +
+30                                         + Search: -------search-------
 */
 
 #[allow(dead_code)]
@@ -55,9 +67,14 @@ pub struct MainItems {
     pub log: Dispatch<Log>,
 }
 
+/// `deck` is the file the log is saved to
 pub fn main_scene(
     full_wh: (u16, u16),
-) -> TerminalResult<(Scene, Vec<(String, Scene)>, MainItems)> {
+    deck: PathBuf,
+    parser: &HangulParser,
+    layouts: &[Layout],
+) -> TerminalResult<(Scene, SubScenes, MainItems)> {
+    let scheme = parser.scheme();
     let mut scene = Scene::default();
     /*
      * Lines
@@ -98,7 +115,7 @@ pub fn main_scene(
         scene.insert_block(
             "hangul-bot".into(),
             LineHorizontal::default()
-                .with_y(4)
+                .with_y(5)
                 .with_line_start(0)
                 .with_length(41)
                 .clone(),
@@ -109,13 +126,13 @@ pub fn main_scene(
                 .with_x(8)
                 .with_z_index(1)
                 .with_line_start(2)
-                .with_length(3)
+                .with_length(4)
                 .clone(),
         )?;
         scene.insert_block(
             "rr-bot".into(),
             LineHorizontal::default()
-                .with_y(6)
+                .with_y(7)
                 .with_line_start(0)
                 .with_length(41)
                 .clone(),
@@ -124,14 +141,14 @@ pub fn main_scene(
             "rr-left".into(),
             LineVertical::default()
                 .with_x(6)
-                .with_line_start(4)
+                .with_line_start(5)
                 .with_length(3)
                 .clone(),
         )?;
         scene.insert_block(
             "desc-bot".into(),
             LineHorizontal::default()
-                .with_y(8)
+                .with_y(9)
                 .with_line_start(0)
                 .with_length(41)
                 .clone(),
@@ -141,14 +158,14 @@ pub fn main_scene(
             LineVertical::default()
                 .with_x(6)
                 .with_z_index(1)
-                .with_line_start(6)
+                .with_line_start(7)
                 .with_length(3)
                 .clone(),
         )?;
         scene.insert_block(
             "save-bot".into(),
             LineHorizontal::default()
-                .with_y(10)
+                .with_y(11)
                 .with_line_start(0)
                 .with_length(41)
                 .clone(),
@@ -156,7 +173,7 @@ pub fn main_scene(
         scene.insert_block(
             "comb-bot".into(),
             LineHorizontal::default()
-                .with_y(14)
+                .with_y(15)
                 .with_line_start(0)
                 .with_length(41)
                 .clone(),
@@ -189,7 +206,15 @@ pub fn main_scene(
                 .with_value("Hangul".into())
                 .clone(),
         )?;
-        let h = Dispatch::from(HangulResult::new((10, 3, 0)));
+        scene.insert_block(
+            "pronunciation-text".into(),
+            TextLine::default()
+                .with_pos(1, 4)
+                .with_width(8)
+                .with_value("Spoken".into())
+                .clone(),
+        )?;
+        let h = Dispatch::from(HangulResult::new((10, 3, 0), parser, layouts));
         scene.insert_block("hangul".into(), h.clone())?;
         h
     };
@@ -198,16 +223,12 @@ pub fn main_scene(
      */
     let rr = {
         let rr = Dispatch::from(RrInput::new(
-            TextLine::default().with_pos(8, 5).with_width(31).clone(),
+            TextLine::default().with_pos(8, 6).with_width(31).clone(),
             hangul_result.clone(),
         ));
         scene.insert_block(
             "rr-text".into(),
-            TextLine::default()
-                .with_pos(1, 5)
-                .with_width(4)
-                .with_value("RR".into())
-                .clone(),
+            ModeInfo::new((1, 6, 0), hangul_result.clone()),
         )?;
         scene.insert_input(rr.clone());
         rr
@@ -217,12 +238,12 @@ pub fn main_scene(
      */
     let description_input = {
         let d = DescriptionInput::from(
-            TextLine::default().with_pos(8, 7).with_width(31),
+            TextLine::default().with_pos(8, 8).with_width(31),
         );
         scene.insert_block(
             "desc-text".into(),
             TextLine::default()
-                .with_pos(1, 7)
+                .with_pos(1, 8)
                 .with_width(4)
                 .with_value("Desc".into())
                 .clone(),
@@ -235,13 +256,43 @@ pub fn main_scene(
      */
     let entry_log = {
         let l = Dispatch::from(
-            Log::new((42, 1, 0), 38, 29)?
+            Log::new(deck, (42, 1, 0), 38, 29)?
                 .with_input_pos((80, 30))
+                .with_scheme(scheme)
                 .clone(),
         );
         scene.insert_input(l.clone());
         l
     };
+    /*
+     * Log search
+     */
+    {
+        scene.insert_block(
+            "search-text".into(),
+            TextLine::default()
+                .with_pos(42, 30)
+                .with_width(7)
+                .with_value("Search:".into())
+                .clone(),
+        )?;
+        scene.insert_input(LogSearch::new(
+            TextLine::default().with_pos(50, 30).with_width(30).clone(),
+            entry_log.clone(),
+        ));
+    }
+    /*
+     * Dictionary form
+     */
+    let lemma_choice = {
+        let rr = rr.clone();
+        let di = description_input.clone();
+        let lg = entry_log.clone();
+        Dispatch::from(LemmaChoice::new((3, 3, 0), move |l| {
+            save_entry(&rr, &di, &lg, l);
+            TerminalCode::PreviousSceneWithFocus(0)
+        }))
+    };
     /*
      * SAVE
      */
@@ -249,8 +300,9 @@ pub fn main_scene(
         let rr = rr.clone();
         let di = description_input.clone();
         let lg = entry_log.clone();
+        let lc = lemma_choice.clone();
         let b = Button::new(
-            (1, 9, 0),
+            (1, 10, 0),
             "SAVE".into(),
             38,
             17,
@@ -267,12 +319,16 @@ pub fn main_scene(
                     );
                 }
 
-                lg.write().unwrap().insert_entry(
-                    rr.read().unwrap().hangul().read().unwrap().str().clone(),
-                    di.read().unwrap().value().to_string(),
-                );
-                rr.write().unwrap().clear();
-                di.write().unwrap().clear();
+                // Conjugated words may be saved in their dictionary form
+                let hangul = rr.read().unwrap().hangul();
+                let hangul = hangul.read().unwrap();
+                if hangul.hanja().is_none()
+                    && lc.write().unwrap().set_seen(hangul.str().clone())
+                {
+                    return TerminalCode::GoToScene("lemma-choice".into());
+                }
+                drop(hangul);
+                save_entry(&rr, &di, &lg, None);
                 TerminalCode::Focus(0)
             }),
         );
@@ -284,14 +340,17 @@ pub fn main_scene(
     {
         scene.insert_block(
             "combinations".into(),
-            PossibleInfo::new((1, 11, 0), hangul_result.clone()),
+            PossibleInfo::new((1, 12, 0), hangul_result.clone()),
         )?;
     }
     /*
      * Jamo index
      */
     {
-        scene.insert_block("jamo-box".into(), JamoInfo::new((0, 15, 0)))?;
+        scene.insert_block(
+            "jamo-box".into(),
+            JamoInfo::new((0, 16, 0), scheme),
+        )?;
     }
 
     let empty_hangul_error =
@@ -302,12 +361,18 @@ pub fn main_scene(
         &[],
         true,
     )?;
+    let conjugation = conjugation_scene(entry_log.clone())?;
+    let lemma_choice = lemma_choice_scene(lemma_choice)?;
+    let entry_details = entry_details_scene(entry_log.clone())?;
 
     Ok((
         scene,
         vec![
             ("empty-hangul-error".into(), empty_hangul_error),
             ("empty-description-error".into(), empty_description_error),
+            ("conjugation".into(), conjugation),
+            ("lemma-choice".into(), lemma_choice),
+            ("entry-details".into(), entry_details),
         ],
         MainItems {
             hangul_result,
@@ -317,3 +382,49 @@ pub fn main_scene(
         },
     ))
 }
+
+/// Asks whether a conjugated word is saved in its dictionary form
+fn lemma_choice_scene(
+    lemma_choice: impl Input + 'static,
+) -> TerminalResult<Scene> {
+    let mut scene = Scene::new(SceneType::PopUp(12, 5));
+    scene.insert_block(
+        "background".into(),
+        Rectangle::new((0, 0, 0), (57, 21), true),
+    )?;
+    scene.insert_block(
+        "heading".into(),
+        TextLine::default()
+            .with_pos(1, 1)
+            .with_width(" Dictionary form:".len() as u16)
+            .with_value(" Dictionary form:".into())
+            .clone(),
+    )?;
+    scene.insert_input(lemma_choice);
+    Ok(scene)
+}
+
+/// Saves the typed word, or `lemma` with the typed word as the form it
+/// was seen in
+fn save_entry(
+    rr: &Dispatch<RrInput>,
+    di: &DescriptionInput,
+    lg: &Dispatch<Log>,
+    lemma: Option<&Lemma>,
+) {
+    let hangul = rr.read().unwrap().hangul();
+    let hangul = hangul.read().unwrap();
+    let (key, seen) = match lemma {
+        Some(l) => (Text::from(l.dictionary.clone()), hangul.str().to_string()),
+        None => (hangul.str().clone(), String::new()),
+    };
+    lg.write().unwrap().insert_entry(
+        key,
+        hangul.hanja().unwrap_or_default().into(),
+        seen,
+        di.read().unwrap().value().to_string(),
+    );
+    drop(hangul);
+    rr.write().unwrap().clear();
+    di.write().unwrap().clear();
+}
@@ -1,5 +1,3 @@
-use std::u16;
-
 use terminal::{
     Scene, SceneType, TerminalResult,
     code::TerminalCode,
@@ -7,8 +5,15 @@ use terminal::{
 };
 
 use crate::{
-    elements::{HangulResult, Log, RrInput},
-    scenes::error_popup_scene,
+    deck::Decks,
+    elements::{
+        BackupList, DeckList, FindResults, HangulResult, Log, NumberQuiz,
+        ParticleDrill, RrInput,
+    },
+    hangul_parser::HangulParser,
+    keyboard::Layout,
+    scenes::SubScenes,
+    text::{Segment, Text},
 };
 
 const WIDTH: u16 = 57;
@@ -21,13 +26,31 @@ const DELETE: &str = "Delete";
 const DELETE_LEN: u16 = 6;
 const FIND: &str = "Find";
 const FIND_LEN: u16 = 4;
+const NUMBERS: &str = "Numbers";
+const NUMBERS_LEN: u16 = 7;
+const PARTICLES: &str = "Particles";
+const PARTICLES_LEN: u16 = 9;
+const DECKS: &str = "Decks";
+const DECKS_LEN: u16 = 5;
+const BACKUPS: &str = "Backups";
+const BACKUPS_LEN: u16 = 7;
+const CREATE: &str = "Create";
+const CREATE_LEN: u16 = 6;
+const RENAME: &str = "Rename";
+const RENAME_LEN: u16 = 6;
+const CHECK: &str = "Check";
+const CHECK_LEN: u16 = 5;
 const CLOSE: &str = "Close";
 const CLOSE_LEN: u16 = 5;
 
+/// `deck` is the one of `decks` that `log` is saved to
 pub fn menu_scene(
-    full_wh: (u16, u16),
     log: Dispatch<Log>,
-) -> TerminalResult<(Scene, Vec<(String, Scene)>)> {
+    decks: Decks,
+    deck: String,
+    parser: &HangulParser,
+    layouts: &[Layout],
+) -> TerminalResult<(Scene, SubScenes)> {
     let mut scene = Scene::new(SceneType::PopUp(12, 5));
     scene.insert_block(
         "background".into(),
@@ -55,6 +78,46 @@ pub fn menu_scene(
         MARGIN,
         Some(|| TerminalCode::ReplaceCurrentScene("delete-menu".into())),
     ));
+    scene.insert_input(Button::new(
+        (centered_x(NUMBERS_LEN + MARGIN_2), 7, 0),
+        NUMBERS.into(),
+        NUMBERS_LEN + MARGIN_2,
+        MARGIN,
+        Some(|| TerminalCode::ReplaceCurrentScene("number-quiz".into())),
+    ));
+    scene.insert_input(Button::new(
+        (centered_x(PARTICLES_LEN + MARGIN_2), 9, 0),
+        PARTICLES.into(),
+        PARTICLES_LEN + MARGIN_2,
+        MARGIN,
+        Some(|| TerminalCode::ReplaceCurrentScene("particle-drill".into())),
+    ));
+    scene.insert_input(Button::new(
+        (centered_x(DECKS_LEN + MARGIN_2), 11, 0),
+        DECKS.into(),
+        DECKS_LEN + MARGIN_2,
+        MARGIN,
+        Some(|| TerminalCode::ReplaceCurrentScene("decks".into())),
+    ));
+    // Listed when opened, backups are taken as decks are opened
+    let backup_list = Dispatch::from(BackupList::new(
+        (MARGIN, 5, 0),
+        HEIGHT - 9,
+        log.clone(),
+    ));
+    {
+        let backup_list = backup_list.clone();
+        scene.insert_input(Button::new(
+            (centered_x(BACKUPS_LEN + MARGIN_2), 13, 0),
+            BACKUPS.into(),
+            BACKUPS_LEN + MARGIN_2,
+            MARGIN,
+            Some(move || {
+                backup_list.write().unwrap().refresh();
+                TerminalCode::ReplaceCurrentScene("backups".into())
+            }),
+        ));
+    }
     scene.insert_input(Button::new(
         (centered_x(CLOSE_LEN + MARGIN_2), HEIGHT - 3, 0),
         CLOSE.into(),
@@ -62,25 +125,31 @@ pub fn menu_scene(
         MARGIN,
         Some(|| TerminalCode::PreviousScene),
     ));
-    let find_scene = find_scene(log.clone())?;
-    let delete_scene = delete_scene(log)?;
-    let not_found_error = error_popup_scene(
-        full_wh,
-        "Could not find given entry!".into(),
-        &[],
-        true,
-    )?;
+    let find_scene = find_scene(log.clone(), parser, layouts)?;
+    let delete_scene = delete_scene(log.clone(), parser, layouts)?;
+    let number_quiz_scene = number_quiz_scene(parser, layouts)?;
+    let particle_drill_scene =
+        particle_drill_scene(log.clone(), parser, layouts)?;
+    let deck_scene = deck_scene(log, decks, deck)?;
+    let backup_scene = backup_scene(backup_list)?;
     Ok((
         scene,
         vec![
             ("find-menu".into(), find_scene),
             ("delete-menu".into(), delete_scene),
-            ("not-found-error".into(), not_found_error),
+            ("number-quiz".into(), number_quiz_scene),
+            ("particle-drill".into(), particle_drill_scene),
+            ("decks".into(), deck_scene),
+            ("backups".into(), backup_scene),
         ],
     ))
 }
 
-fn find_scene(log: Dispatch<Log>) -> TerminalResult<Scene> {
+fn find_scene(
+    log: Dispatch<Log>,
+    parser: &HangulParser,
+    layouts: &[Layout],
+) -> TerminalResult<Scene> {
     let mut scene = Scene::new(SceneType::PopUp(12, 5));
     scene.insert_block(
         "background".into(),
@@ -98,7 +167,11 @@ fn find_scene(log: Dispatch<Log>) -> TerminalResult<Scene> {
      * Hangul
      */
     let hangul_result = {
-        let h = Dispatch::from(HangulResult::new((centered_x(10), 3, 0)));
+        let h = Dispatch::from(HangulResult::new(
+            (centered_x(10), 3, 0),
+            parser,
+            layouts,
+        ));
         scene.insert_block("hangul".into(), h.clone())?;
         h
     };
@@ -116,6 +189,22 @@ fn find_scene(log: Dispatch<Log>) -> TerminalResult<Scene> {
         scene.insert_input(rr.clone());
         rr
     };
+    /*
+     * Results
+     */
+    let results = {
+        let log = log.clone();
+        let r = Dispatch::from(FindResults::new(
+            (MARGIN, 8, 0),
+            HEIGHT - 12,
+            move |key| {
+                log.write().unwrap().index_at_sense(key);
+                TerminalCode::PreviousSceneWithFocus(4)
+            },
+        ));
+        scene.insert_input(r.clone());
+        r
+    };
     /*
      * Button
      */
@@ -125,15 +214,47 @@ fn find_scene(log: Dispatch<Log>) -> TerminalResult<Scene> {
         FIND_LEN + MARGIN_2,
         MARGIN,
         Some(move || {
-            let found = log
-                .write()
-                .unwrap()
-                .index_at(rr.write().unwrap().hangul().read().unwrap().str());
+            let hangul = rr.read().unwrap().hangul();
+            let hangul = hangul.read().unwrap();
+            // Bare initials (초성) list every entry they start syllables of,
+            // typed jamo are read as Hangul
+            let query = Text::from(hangul.str().to_string().as_str());
+            if let [Segment::Hangul(initials)] = query.as_slice()
+                && initials.is_initials()
+            {
+                let found = log.read().unwrap().find_initials(initials);
+                let focus = if found.is_empty() { 0 } else { 2 };
+                results.write().unwrap().set_results(found);
+                drop(hangul);
+                rr.write().unwrap().clear();
+                return TerminalCode::Focus(focus);
+            }
+            // A word of several senses lists them to choose from
+            let senses = log.read().unwrap().senses(hangul.str());
+            if senses.len() > 1 {
+                results.write().unwrap().set_results(senses);
+                drop(hangul);
+                rr.write().unwrap().clear();
+                return TerminalCode::Focus(2);
+            }
+            // Entries typed in Hanja are found by their Hanja
+            let found = match hangul.hanja() {
+                Some(hanja) => log.write().unwrap().index_at_hanja(hanja),
+                None => log.write().unwrap().index_at(hangul.str()),
+            };
+            // Otherwise the closest entries are listed
+            let results_found = !found && {
+                let found = log.read().unwrap().fuzzy_find(hangul.str());
+                let mut results = results.write().unwrap();
+                results.set_results(found);
+                !results.is_empty()
+            };
+            drop(hangul);
             rr.write().unwrap().clear();
-            if found {
-                TerminalCode::PreviousSceneWithFocus(3)
-            } else {
-                TerminalCode::ReplaceCurrentScene("not-found-error".into())
+            match (found, results_found) {
+                (true, _) => TerminalCode::PreviousSceneWithFocus(4),
+                (false, true) => TerminalCode::Focus(2),
+                (false, false) => TerminalCode::Focus(0),
             }
         }),
     ));
@@ -148,7 +269,11 @@ fn find_scene(log: Dispatch<Log>) -> TerminalResult<Scene> {
     Ok(scene)
 }
 
-fn delete_scene(log: Dispatch<Log>) -> TerminalResult<Scene> {
+fn delete_scene(
+    log: Dispatch<Log>,
+    parser: &HangulParser,
+    layouts: &[Layout],
+) -> TerminalResult<Scene> {
     let mut scene = Scene::new(SceneType::PopUp(12, 5));
     scene.insert_block(
         "background".into(),
@@ -166,7 +291,11 @@ fn delete_scene(log: Dispatch<Log>) -> TerminalResult<Scene> {
      * Hangul
      */
     let hangul_result = {
-        let h = Dispatch::from(HangulResult::new((centered_x(10), 3, 0)));
+        let h = Dispatch::from(HangulResult::new(
+            (centered_x(10), 3, 0),
+            parser,
+            layouts,
+        ));
         scene.insert_block("hangul".into(), h.clone())?;
         h
     };
@@ -184,6 +313,22 @@ fn delete_scene(log: Dispatch<Log>) -> TerminalResult<Scene> {
         scene.insert_input(rr.clone());
         rr
     };
+    /*
+     * Senses
+     */
+    let results = {
+        let log = log.clone();
+        let r = Dispatch::from(FindResults::new(
+            (MARGIN, 8, 0),
+            HEIGHT - 12,
+            move |key| {
+                log.write().unwrap().remove_entry(key);
+                TerminalCode::PreviousScene
+            },
+        ));
+        scene.insert_input(r.clone());
+        r
+    };
     /*
      * Button
      */
@@ -193,11 +338,321 @@ fn delete_scene(log: Dispatch<Log>) -> TerminalResult<Scene> {
         DELETE_LEN + MARGIN_2,
         MARGIN,
         Some(move || {
-            log.write().unwrap().remove_entry(
-                rr.write().unwrap().hangul().read().unwrap().str(),
-            );
+            let hangul =
+                rr.read().unwrap().hangul().read().unwrap().str().clone();
+            rr.write().unwrap().clear();
+            // Which sense of a word of several to delete is chosen
+            let mut senses = log.read().unwrap().senses(&hangul);
+            match senses.len() {
+                0 => TerminalCode::PreviousScene,
+                1 => {
+                    let (key, _) = senses.remove(0);
+                    log.write().unwrap().remove_entry(&key);
+                    TerminalCode::PreviousScene
+                }
+                _ => {
+                    results.write().unwrap().set_results(senses);
+                    TerminalCode::Focus(2)
+                }
+            }
+        }),
+    ));
+    scene.insert_input(Button::new(
+        (centered_x(CLOSE_LEN + MARGIN_2), HEIGHT - 3, 0),
+        CLOSE.into(),
+        CLOSE_LEN + MARGIN_2,
+        MARGIN,
+        Some(|| TerminalCode::PreviousScene),
+    ));
+
+    Ok(scene)
+}
+
+/// Create, rename, open and delete decks
+fn deck_scene(
+    log: Dispatch<Log>,
+    decks: Decks,
+    deck: String,
+) -> TerminalResult<Scene> {
+    let mut scene = Scene::new(SceneType::PopUp(12, 5));
+    scene.insert_block(
+        "background".into(),
+        Rectangle::new((0, 0, 0), (WIDTH, HEIGHT), true),
+    )?;
+    scene.insert_block(
+        "heading".into(),
+        TextLine::default()
+            .with_pos(centered_x(DECKS_LEN), 1)
+            .with_width(u16::MAX)
+            .with_value(DECKS.into())
+            .clone(),
+    )?;
+    scene.insert_block(
+        "decks-dir".into(),
+        TextLine::default()
+            .with_pos(MARGIN, HEIGHT - 2)
+            .with_width(WIDTH - MARGIN_2)
+            .with_value(decks.dir().display().to_string())
+            .clone(),
+    )?;
+    /*
+     * Name
+     */
+    scene.insert_block(
+        "name-text".into(),
+        TextLine::default()
+            .with_pos(MARGIN, 3)
+            .with_width(5)
+            .with_value("Name:".into())
+            .clone(),
+    )?;
+    let name = {
+        let n = Dispatch::from(
+            TextLine::default()
+                .with_pos(MARGIN + 6, 3)
+                .with_width(WIDTH - MARGIN_2 - 6)
+                .clone(),
+        );
+        scene.insert_input(n.clone());
+        n
+    };
+    /*
+     * Decks, Enter opens one
+     */
+    let list = {
+        let l = Dispatch::from(DeckList::new(
+            (MARGIN, 7, 0),
+            HEIGHT - 11,
+            decks,
+            deck,
+            log,
+        ));
+        scene.insert_input(l.clone());
+        l
+    };
+    /*
+     * Buttons, acting on the name and the chosen deck
+     */
+    {
+        let (name, list) = (name.clone(), list.clone());
+        scene.insert_input(Button::new(
+            (MARGIN, 5, 0),
+            CREATE.into(),
+            CREATE_LEN + MARGIN_2,
+            MARGIN,
+            Some(move || {
+                let value = name.read().unwrap().value().trim().to_string();
+                match list.write().unwrap().create(&value) {
+                    true => {
+                        name.write().unwrap().clear();
+                        TerminalCode::PreviousScene
+                    }
+                    false => TerminalCode::None,
+                }
+            }),
+        ));
+    }
+    {
+        let (name, list) = (name.clone(), list.clone());
+        scene.insert_input(Button::new(
+            (centered_x(RENAME_LEN + MARGIN_2), 5, 0),
+            RENAME.into(),
+            RENAME_LEN + MARGIN_2,
+            MARGIN,
+            Some(move || {
+                let value = name.read().unwrap().value().trim().to_string();
+                if list.write().unwrap().rename_chosen(&value) {
+                    name.write().unwrap().clear();
+                }
+                TerminalCode::None
+            }),
+        ));
+    }
+    scene.insert_input(Button::new(
+        (WIDTH - MARGIN - DELETE_LEN - MARGIN_2, 5, 0),
+        DELETE.into(),
+        DELETE_LEN + MARGIN_2,
+        MARGIN,
+        Some(move || {
+            list.write().unwrap().delete_chosen();
+            TerminalCode::None
+        }),
+    ));
+    scene.insert_input(Button::new(
+        (centered_x(CLOSE_LEN + MARGIN_2), HEIGHT - 4, 0),
+        CLOSE.into(),
+        CLOSE_LEN + MARGIN_2,
+        MARGIN,
+        Some(|| TerminalCode::PreviousScene),
+    ));
+
+    Ok(scene)
+}
+
+/// Restore the log from one of its backups
+fn backup_scene(list: Dispatch<BackupList>) -> TerminalResult<Scene> {
+    let mut scene = Scene::new(SceneType::PopUp(12, 5));
+    scene.insert_block(
+        "background".into(),
+        Rectangle::new((0, 0, 0), (WIDTH, HEIGHT), true),
+    )?;
+    scene.insert_block(
+        "heading".into(),
+        TextLine::default()
+            .with_pos(centered_x(BACKUPS_LEN), 1)
+            .with_width(u16::MAX)
+            .with_value(BACKUPS.into())
+            .clone(),
+    )?;
+    scene.insert_block(
+        "help".into(),
+        TextLine::default()
+            .with_pos(MARGIN, 3)
+            .with_width(WIDTH - MARGIN_2)
+            .with_value("Enter restores, the log is backed up first".into())
+            .clone(),
+    )?;
+    scene.insert_input(list);
+    scene.insert_input(Button::new(
+        (centered_x(CLOSE_LEN + MARGIN_2), HEIGHT - 3, 0),
+        CLOSE.into(),
+        CLOSE_LEN + MARGIN_2,
+        MARGIN,
+        Some(|| TerminalCode::PreviousScene),
+    ));
+
+    Ok(scene)
+}
+
+/// Practice reading numbers, counters, dates and times
+fn number_quiz_scene(
+    parser: &HangulParser,
+    layouts: &[Layout],
+) -> TerminalResult<Scene> {
+    let mut scene = Scene::new(SceneType::PopUp(12, 5));
+    scene.insert_block(
+        "background".into(),
+        Rectangle::new((0, 0, 0), (WIDTH, HEIGHT), true),
+    )?;
+    scene.insert_block(
+        "heading".into(),
+        TextLine::default()
+            .with_pos(centered_x(NUMBERS_LEN), 1)
+            .with_width(u16::MAX)
+            .with_value(NUMBERS.into())
+            .clone(),
+    )?;
+    let quiz = Dispatch::from(NumberQuiz::new((MARGIN, 3, 0)));
+    scene.insert_block("quiz".into(), quiz.clone())?;
+    /*
+     * Hangul
+     */
+    let hangul_result = {
+        let h =
+            Dispatch::from(HangulResult::new((MARGIN, 7, 0), parser, layouts));
+        scene.insert_block("hangul".into(), h.clone())?;
+        h
+    };
+    /*
+     * rr
+     */
+    let rr = {
+        let rr = Dispatch::from(RrInput::new(
+            TextLine::default()
+                .with_pos(MARGIN, 9)
+                .with_width(WIDTH - MARGIN_2)
+                .clone(),
+            hangul_result.clone(),
+        ));
+        scene.insert_input(rr.clone());
+        rr
+    };
+    /*
+     * Button
+     */
+    scene.insert_input(Button::new(
+        (centered_x(CHECK_LEN + MARGIN_2), 11, 0),
+        CHECK.into(),
+        CHECK_LEN + MARGIN_2,
+        MARGIN,
+        Some(move || {
+            quiz.write()
+                .unwrap()
+                .check(rr.read().unwrap().hangul().read().unwrap().str());
+            rr.write().unwrap().clear();
+            TerminalCode::Focus(0)
+        }),
+    ));
+    scene.insert_input(Button::new(
+        (centered_x(CLOSE_LEN + MARGIN_2), HEIGHT - 3, 0),
+        CLOSE.into(),
+        CLOSE_LEN + MARGIN_2,
+        MARGIN,
+        Some(|| TerminalCode::PreviousScene),
+    ));
+
+    Ok(scene)
+}
+
+/// Practice attaching particles to the nouns of `log`
+fn particle_drill_scene(
+    log: Dispatch<Log>,
+    parser: &HangulParser,
+    layouts: &[Layout],
+) -> TerminalResult<Scene> {
+    let mut scene = Scene::new(SceneType::PopUp(12, 5));
+    scene.insert_block(
+        "background".into(),
+        Rectangle::new((0, 0, 0), (WIDTH, HEIGHT), true),
+    )?;
+    scene.insert_block(
+        "heading".into(),
+        TextLine::default()
+            .with_pos(centered_x(PARTICLES_LEN), 1)
+            .with_width(u16::MAX)
+            .with_value(PARTICLES.into())
+            .clone(),
+    )?;
+    let drill = Dispatch::from(ParticleDrill::new((MARGIN, 3, 0), log));
+    scene.insert_block("drill".into(), drill.clone())?;
+    /*
+     * Hangul
+     */
+    let hangul_result = {
+        let h =
+            Dispatch::from(HangulResult::new((MARGIN, 7, 0), parser, layouts));
+        scene.insert_block("hangul".into(), h.clone())?;
+        h
+    };
+    /*
+     * rr
+     */
+    let rr = {
+        let rr = Dispatch::from(RrInput::new(
+            TextLine::default()
+                .with_pos(MARGIN, 9)
+                .with_width(WIDTH - MARGIN_2)
+                .clone(),
+            hangul_result.clone(),
+        ));
+        scene.insert_input(rr.clone());
+        rr
+    };
+    /*
+     * Button
+     */
+    scene.insert_input(Button::new(
+        (centered_x(CHECK_LEN + MARGIN_2), 11, 0),
+        CHECK.into(),
+        CHECK_LEN + MARGIN_2,
+        MARGIN,
+        Some(move || {
+            drill
+                .write()
+                .unwrap()
+                .check(rr.read().unwrap().hangul().read().unwrap().str());
             rr.write().unwrap().clear();
-            TerminalCode::PreviousScene
+            TerminalCode::Focus(0)
         }),
     ));
     scene.insert_input(Button::new(
@@ -1,9 +1,18 @@
+mod conjugation;
+mod entry_details;
 mod error_popup;
 mod help_menu;
 mod main;
 mod menu;
 
+pub use conjugation::conjugation_scene;
+pub use entry_details::entry_details_scene;
 pub use error_popup::error_popup_scene;
 pub use help_menu::help_menu_scene;
 pub use main::{MainItems, main_scene};
 pub use menu::menu_scene;
+
+use terminal::Scene;
+
+/// Named scenes that a scene builder wants registered next to its own scene
+pub type SubScenes = Vec<(String, Scene)>;
@@ -0,0 +1,218 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+use csv::{ReaderBuilder, WriterBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::text::Text;
+
+/// Version of the log file written, see [`migrate`]
+pub const VERSION: u32 = 1;
+/// First line of a versioned log file, followed by its version
+const VERSION_LINE: &str = "# langlog schema ";
+
+/// ## Part Of Speech
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PartOfSpeech {
+    Noun,
+    Pronoun,
+    Numeral,
+    Verb,
+    Adjective,
+    Adverb,
+    Determiner,
+    Particle,
+    Interjection,
+    Expression,
+}
+impl PartOfSpeech {
+    pub fn all() -> [PartOfSpeech; 10] {
+        [
+            PartOfSpeech::Noun,
+            PartOfSpeech::Pronoun,
+            PartOfSpeech::Numeral,
+            PartOfSpeech::Verb,
+            PartOfSpeech::Adjective,
+            PartOfSpeech::Adverb,
+            PartOfSpeech::Determiner,
+            PartOfSpeech::Particle,
+            PartOfSpeech::Interjection,
+            PartOfSpeech::Expression,
+        ]
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PartOfSpeech::Noun => "noun",
+            PartOfSpeech::Pronoun => "pronoun",
+            PartOfSpeech::Numeral => "numeral",
+            PartOfSpeech::Verb => "verb",
+            PartOfSpeech::Adjective => "adjective",
+            PartOfSpeech::Adverb => "adverb",
+            PartOfSpeech::Determiner => "determiner",
+            PartOfSpeech::Particle => "particle",
+            PartOfSpeech::Interjection => "interjection",
+            PartOfSpeech::Expression => "expression",
+        }
+    }
+}
+
+/// ## Row
+/// A log entry as it is written to disk. Columns are read by name, so
+/// files missing any but `hangul` and `description` still load.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Row {
+    pub hangul: Text,
+    #[serde(default)]
+    pub hanja: String,
+    #[serde(default)]
+    pub seen: String,
+    pub description: String,
+    #[serde(default)]
+    pub part_of_speech: Option<PartOfSpeech>,
+    /// Comma separated
+    #[serde(default)]
+    pub tags: String,
+    #[serde(default)]
+    pub example: String,
+    #[serde(default)]
+    pub notes: String,
+    /// Seconds since the Unix epoch, 0 if unknown
+    #[serde(default, alias = "added")]
+    pub created: u64,
+    /// Seconds since the Unix epoch, 0 if unknown
+    #[serde(default)]
+    pub modified: u64,
+}
+
+/// ## Read
+/// Rows of the log file at `path`, upgraded to the current version. Fails
+/// on any row which can not be read, or a file of a newer version, rather
+/// than lose what it does not understand.
+pub fn read(path: &Path) -> io::Result<Vec<Row>> {
+    parse(&fs::read_to_string(path)?)
+}
+
+/// ## Write
+/// Writes `rows` to `path` in the current version. They are written to
+/// a temporary file first, which replaces the log once complete, so a
+/// failed write leaves the log as it was.
+pub fn write(
+    path: &Path,
+    rows: impl IntoIterator<Item = Row>,
+) -> io::Result<()> {
+    let mut wtr = WriterBuilder::new()
+        .delimiter(b';')
+        .has_headers(true)
+        .from_writer(vec![]);
+    for row in rows {
+        wtr.serialize(row)?;
+    }
+    let csv = wtr.into_inner().map_err(|e| e.into_error())?;
+    let mut content = format!("{}{}\n", VERSION_LINE, VERSION).into_bytes();
+    content.extend(csv);
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let mut file = fs::File::create(&tmp)?;
+    file.write_all(&content)?;
+    file.sync_all()?;
+    fs::rename(tmp, path)
+}
+
+fn parse(content: &str) -> io::Result<Vec<Row>> {
+    // Files from before versioning start with the header
+    let (version, csv) = match content.split_once('\n') {
+        Some((first, rest)) if first.starts_with(VERSION_LINE) => {
+            let version = &first[VERSION_LINE.len()..];
+            match version.trim().parse() {
+                Ok(v) if v > VERSION => {
+                    return Err(invalid(format!(
+                        "Log is of schema {}, newer than {}",
+                        v, VERSION
+                    )));
+                }
+                Ok(v) => (v, rest),
+                Err(_) => {
+                    return Err(invalid(format!(
+                        "Unknown schema {:?}",
+                        version
+                    )));
+                }
+            }
+        }
+        _ => (0, content),
+    };
+    let rows = ReaderBuilder::new()
+        .delimiter(b';')
+        .from_reader(csv.as_bytes())
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+    Ok(migrate(version, rows))
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Upgrades `rows` read from a file of `version`
+/// - 0: Unversioned, from two columns of `hangul;description` up to
+///   `hanja`, `seen` and an `added` timestamp
+fn migrate(version: u32, mut rows: Vec<Row>) -> Vec<Row> {
+    if version < 1 {
+        log::info!("Upgrading log from version {} to {}", version, VERSION);
+        for row in rows.iter_mut() {
+            row.modified = row.created;
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{PartOfSpeech, VERSION, VERSION_LINE, parse};
+
+    #[test]
+    fn test_migrate() {
+        let legacy =
+            parse("hangul;description\n집;House\n학교;School\n").unwrap();
+        assert_eq!(legacy.len(), 2);
+        assert_eq!(legacy[0].hangul.to_string(), "집");
+        assert_eq!(legacy[1].description, "School");
+        assert!(legacy[1].hanja.is_empty() && legacy[1].created == 0);
+
+        let added = parse(
+            "hangul;hanja;seen;description;added\n가다;;갔어요;To go;100\n",
+        )
+        .unwrap();
+        assert_eq!(added[0].seen, "갔어요");
+        assert_eq!((added[0].created, added[0].modified), (100, 100));
+
+        let current = parse(&format!(
+            "{}{}\n{}\n{}\n",
+            VERSION_LINE,
+            VERSION,
+            "hangul;hanja;seen;description;part_of_speech;tags;example;\
+             notes;created;modified",
+            "집;;;House;noun;home, place;집에 가요;;100;200"
+        ))
+        .unwrap();
+        assert_eq!(current[0].part_of_speech, Some(PartOfSpeech::Noun));
+        assert_eq!(current[0].tags, "home, place");
+        assert_eq!((current[0].created, current[0].modified), (100, 200));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let header = "hangul;description\n";
+        assert!(parse(&format!("{}집;House\n학교\n", header)).is_err());
+        assert!(
+            parse(&format!("{}{}\n{}", VERSION_LINE, VERSION + 1, header))
+                .is_err()
+        );
+        assert!(parse(&format!("{}one\n{}", VERSION_LINE, header)).is_err());
+    }
+}
@@ -0,0 +1,220 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::{Bound, Range},
+};
+
+use crate::text::{Segment, Text};
+
+/// ## Decompose
+/// Characters of `text` with every syllable split into its jamo, e.g.
+/// `한국` is `ㅎㅏㄴㄱㅜㄱ`
+pub fn decompose(text: &Text) -> Vec<char> {
+    let mut ret = vec![];
+    for segment in text.iter() {
+        match segment {
+            Segment::Hangul(h) => {
+                ret.extend(h.iter().flat_map(|s| s.jamo()).map(char::from))
+            }
+            Segment::Literal(l) => ret.extend(l.chars()),
+        }
+    }
+    ret
+}
+
+/// ## Jamo distance
+/// Edit distance between the decomposed forms of `a` and `b`, so that
+/// `한굮` is one edit away from `한국`
+pub fn jamo_distance(a: &Text, b: &Text) -> usize {
+    edit_distance(&decompose(a), &decompose(b))
+}
+
+/// Most edits for `query` to still match, about one in three jamo
+pub fn max_distance(query: &Text) -> usize {
+    (decompose(query).len() / 3).max(1)
+}
+
+/// ## Description Index
+/// Lowercase words of every description, for looking entries up by the
+/// start of a word, and the lowercase descriptions for anything else
+#[derive(Debug, Clone)]
+pub struct DescriptionIndex<K> {
+    words: BTreeMap<String, BTreeSet<K>>,
+    descriptions: BTreeMap<K, String>,
+}
+impl<K: Ord + Clone> DescriptionIndex<K> {
+    pub fn insert(&mut self, key: &K, description: &str) {
+        self.remove(key);
+        let description = description.to_lowercase();
+        for word in words(&description) {
+            self.words
+                .entry(word.into())
+                .or_default()
+                .insert(key.clone());
+        }
+        self.descriptions.insert(key.clone(), description);
+    }
+
+    pub fn remove(&mut self, key: &K) {
+        let description = match self.descriptions.remove(key) {
+            Some(d) => d,
+            None => return,
+        };
+        for word in words(&description) {
+            if let Some(keys) = self.words.get_mut(word) {
+                keys.remove(key);
+                if keys.is_empty() {
+                    self.words.remove(word);
+                }
+            }
+        }
+    }
+
+    /// Entries whose description contains every word of `query`, ignoring
+    /// case. Those where each word starts a word of the description come
+    /// first, e.g. `hou` finds `house` before `a thousand`.
+    pub fn search(&self, query: &str) -> Vec<K> {
+        let query = query.to_lowercase();
+        let terms = words(&query).collect::<Vec<_>>();
+        if terms.is_empty() {
+            return vec![];
+        }
+        let mut prefixed: Option<BTreeSet<&K>> = None;
+        for term in terms.iter() {
+            let keys = self
+                .words
+                .range::<str, _>((Bound::Included(*term), Bound::Unbounded))
+                .take_while(|(w, _)| w.starts_with(term))
+                .flat_map(|(_, keys)| keys)
+                .collect::<BTreeSet<_>>();
+            prefixed = Some(match prefixed {
+                Some(p) => p.intersection(&keys).copied().collect(),
+                None => keys,
+            });
+        }
+        let prefixed = prefixed.unwrap_or_default();
+        let inside = self.descriptions.iter().filter(|(k, d)| {
+            !prefixed.contains(k) && terms.iter().all(|t| d.contains(t))
+        });
+        prefixed
+            .iter()
+            .copied()
+            .chain(inside.map(|(k, _)| k))
+            .cloned()
+            .collect()
+    }
+}
+
+impl<K> Default for DescriptionIndex<K> {
+    fn default() -> Self {
+        Self {
+            words: Default::default(),
+            descriptions: Default::default(),
+        }
+    }
+}
+
+/// Byte ranges of `line` matching a word of `query`, ignoring case
+pub fn match_ranges(line: &str, query: &str) -> Vec<Range<usize>> {
+    let terms = words(query).collect::<Vec<_>>();
+    let mut ret: Vec<Range<usize>> = vec![];
+    for (start, _) in line.char_indices() {
+        let len = match terms
+            .iter()
+            .filter_map(|t| match_at(&line[start..], t))
+            .max()
+        {
+            Some(len) => len,
+            None => continue,
+        };
+        match ret.last_mut() {
+            Some(last) if last.end >= start => {
+                last.end = last.end.max(start + len)
+            }
+            _ => ret.push(start..start + len),
+        }
+    }
+    ret
+}
+
+/// Bytes of `s` matching `term` from its start, ignoring case
+fn match_at(s: &str, term: &str) -> Option<usize> {
+    let mut chars = s.char_indices();
+    for t in term.chars() {
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(t.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(s.len(), |(i, _)| i))
+}
+
+fn words(s: &str) -> impl Iterator<Item = &str> {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+}
+
+/// Levenshtein distance
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        search::{DescriptionIndex, decompose, jamo_distance, match_ranges},
+        text::Text,
+    };
+
+    #[test]
+    fn test_jamo_distance() {
+        let distance = |a, b| jamo_distance(&Text::from(a), &Text::from(b));
+        assert_eq!(
+            decompose(&Text::from("한국")),
+            "ㅎㅏㄴㄱㅜㄱ".chars().collect::<Vec<_>>()
+        );
+        assert_eq!(distance("한국", "한국"), 0);
+        assert_eq!(distance("한굮", "한국"), 1);
+        assert_eq!(distance("갑", "감"), 1);
+        assert_eq!(distance("가", "감"), 1);
+        assert_eq!(distance("한국", "중국"), 3);
+        assert_eq!(distance("K-pop", "K-팝"), 3);
+    }
+
+    #[test]
+    fn test_description_index() {
+        let mut index = DescriptionIndex::default();
+        index.insert(&Text::from("집"), "House, home");
+        index.insert(&Text::from("천"), "A thousand");
+        index.insert(&Text::from("학교"), "School");
+        let search = |index: &DescriptionIndex<Text>, query| {
+            index
+                .search(query)
+                .iter()
+                .map(Text::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(search(&index, "hou"), ["집", "천"]);
+        assert_eq!(search(&index, "HOME hou"), ["집"]);
+        assert_eq!(search(&index, "chool"), ["학교"]);
+        assert!(search(&index, "").is_empty());
+        index.remove(&Text::from("집"));
+        assert_eq!(search(&index, "hou"), ["천"]);
+        index.insert(&Text::from("천"), "Cloth");
+        assert!(search(&index, "thousand").is_empty());
+        assert_eq!(match_ranges("House, hOme", "ho"), [0..2, 7..9]);
+        assert_eq!(
+            match_ranges("집 (house) 집", "use hou 집"),
+            [0..3, 5..10, 12..15]
+        );
+    }
+}
@@ -28,6 +28,18 @@ pub struct Syllable {
     finale: Option<FinalJamo>,
 }
 impl Syllable {
+    pub fn new(
+        initial: InitialJamo,
+        medial: MedialJamo,
+        finale: Option<FinalJamo>,
+    ) -> Self {
+        Self {
+            initial: Some(initial),
+            medial: Some(medial),
+            finale,
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         self.state() == State::Start
     }
@@ -75,6 +87,36 @@ impl Syllable {
         self.finale
     }
 
+    pub fn set_initial(&mut self, initial: InitialJamo) {
+        self.initial = Some(initial);
+    }
+
+    /// Only applies to complete syllables, i.e. a syllable consisting of
+    /// an `Initial` and a `Medial`
+    pub fn set_finale(&mut self, finale: Option<FinalJamo>) {
+        if self.medial.is_some() {
+            self.finale = finale;
+        }
+    }
+
+    /// Decomposed (NFD) form, i.e. a sequence of conjoining jamo
+    pub fn nfd(&self) -> String {
+        let mut ret = String::new();
+        ret.extend(self.initial.map(char::from));
+        ret.extend(self.medial.map(char::from));
+        ret.extend(self.finale.map(char::from));
+        ret
+    }
+
+    /// Initial, medial and final as compatibility jamo
+    pub fn jamo(&self) -> Vec<Jamo> {
+        let mut ret = vec![];
+        ret.extend(self.initial.map(Jamo::from));
+        ret.extend(self.medial.map(Jamo::from));
+        ret.extend(self.finale.map(Jamo::from));
+        ret
+    }
+
     /// .
     /// # Push
     /// Appends `jamo` to syllable if applicable.
@@ -98,7 +140,7 @@ impl Syllable {
     ///
     /// No matter the `state`:
     /// - Appending `Jamo` returned by `.possible()`, will not result
-    /// in any errors or overflow.
+    ///   in any errors or overflow.
     ///
     pub fn push(&mut self, jamo: Jamo) -> SyllableResult<Option<Syllable>> {
         match self.state() {
@@ -291,7 +333,7 @@ impl TryFrom<&Jamo> for Syllable {
 
     fn try_from(value: &Jamo) -> SyllableResult<Self> {
         let mut syl = Self::default();
-        syl.push(value.clone())?;
+        syl.push(*value)?;
         Ok(syl)
     }
 }
@@ -299,21 +341,27 @@ impl TryFrom<char> for Syllable {
     type Error = SyllableError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
-        let code = value as u16;
-        if code < 0xac00 || code > 0xd7a3 {
+        let code = value as u32;
+        if !(0xac00..=0xd7a3).contains(&code) {
             return Err(SyllableError::InvalidChar(value));
         }
 
-        let code = code - 0xac00;
+        let code = (code - 0xac00) as u16;
         let ini = code / 588;
         let code = code % 588;
         let med = code / 28;
         let code = code % 28;
         let fin = if code == 0 { None } else { Some(code) };
         Ok(Self {
-            initial: Some(unsafe { transmute(InitialJamo::G as u16 + ini) }),
-            medial: Some(unsafe { transmute(MedialJamo::A as u16 + med) }),
-            finale: fin.map(|f| unsafe { transmute(FinalJamo::G as u16 + f) }),
+            initial: Some(unsafe {
+                transmute::<u16, InitialJamo>(InitialJamo::G as u16 + ini)
+            }),
+            medial: Some(unsafe {
+                transmute::<u16, MedialJamo>(MedialJamo::A as u16 + med)
+            }),
+            finale: fin.map(|f| unsafe {
+                transmute::<u16, FinalJamo>(FinalJamo::G as u16 + f - 1)
+            }),
         })
     }
 }
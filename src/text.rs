@@ -0,0 +1,182 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{hangul::Hangul, romanization::Scheme, syllable::Syllable};
+
+/// ## Segment
+/// Part of a `Text`, either a run of Hangul or any other characters
+/// which are kept verbatim
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Segment {
+    Hangul(Hangul),
+    Literal(String),
+}
+impl Display for Segment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Segment::Hangul(h) => write!(f, "{}", h),
+            Segment::Literal(s) => f.write_str(s),
+        }
+    }
+}
+
+/// ## Text
+/// Mixed script text such as "안녕 하세요", "K-pop 노래" or "1월"
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Text(Vec<Segment>);
+impl Text {
+    /// Appends `c` to the last Hangul run if it can be read as Hangul,
+    /// otherwise to the last literal
+    pub fn push_char(&mut self, c: char) {
+        if let Some(Segment::Hangul(h)) = self.last_mut()
+            && h.push_char(c).is_ok()
+        {
+            return;
+        }
+        let mut hangul = Hangul::default();
+        if hangul.push_char(c).is_ok() {
+            self.push(Segment::Hangul(hangul));
+            return;
+        }
+        match self.last_mut() {
+            Some(Segment::Literal(s)) => s.push(c),
+            _ => self.push(Segment::Literal(c.into())),
+        }
+    }
+
+    pub fn push_syllable(&mut self, syllable: Syllable) {
+        match self.last_mut() {
+            Some(Segment::Hangul(h)) => h.push(syllable),
+            _ => self.push(Segment::Hangul(syllable.into())),
+        }
+    }
+
+    pub fn push_literal(&mut self, literal: &str) {
+        match self.last_mut() {
+            Some(Segment::Literal(s)) => s.push_str(literal),
+            _ => self.push(Segment::Literal(literal.into())),
+        }
+    }
+
+    /// Removes the last syllable or literal character
+    pub fn pop_back(&mut self) {
+        match self.last_mut() {
+            Some(Segment::Hangul(h)) => {
+                h.pop();
+            }
+            Some(Segment::Literal(s)) => {
+                s.pop();
+            }
+            None => return,
+        }
+        self.0.pop_if(|s| match s {
+            Segment::Hangul(h) => h.is_empty(),
+            Segment::Literal(s) => s.is_empty(),
+        });
+    }
+
+    /// Spoken form of every Hangul run, literals are kept as is
+    pub fn pronounce(&self) -> Text {
+        self.map_hangul(|h| Segment::Hangul(h.pronounce()))
+    }
+
+    /// Romanization of every Hangul run, literals are kept as is
+    pub fn romanize(&self, scheme: Scheme) -> String {
+        self.map_hangul(|h| Segment::Literal(h.romanize(scheme)))
+            .to_string()
+    }
+
+    fn map_hangul(&self, f: impl Fn(&Hangul) -> Segment) -> Text {
+        self.iter()
+            .map(|s| match s {
+                Segment::Hangul(h) => f(h),
+                l => l.clone(),
+            })
+            .collect()
+    }
+}
+impl Deref for Text {
+    type Target = Vec<Segment>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Text {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl Display for Text {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for segment in self.iter() {
+            write!(f, "{}", segment)?;
+        }
+        Ok(())
+    }
+}
+impl FromIterator<Segment> for Text {
+    fn from_iter<T: IntoIterator<Item = Segment>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+impl From<Hangul> for Text {
+    fn from(value: Hangul) -> Self {
+        Self(vec![Segment::Hangul(value)])
+    }
+}
+impl From<&str> for Text {
+    fn from(value: &str) -> Self {
+        let mut text = Self::default();
+        for c in value.chars() {
+            text.push_char(c);
+        }
+        text
+    }
+}
+impl Serialize for Text {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+impl<'de> Deserialize<'de> for Text {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let as_string = <&str>::deserialize(deserializer)?;
+        Ok(Text::from(as_string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        romanization::Scheme,
+        text::{Segment, Text},
+    };
+
+    #[test]
+    fn test_text() {
+        let text = Text::from("K-pop 노래");
+        assert_eq!(text.len(), 2);
+        assert_eq!(text[0], Segment::Literal("K-pop ".into()));
+        assert_eq!(text.to_string(), "K-pop 노래");
+        assert_eq!(Text::from("1월").romanize(Scheme::Rr), "1wol");
+        assert_eq!(
+            Text::from("안녕 하세요").romanize(Scheme::Rr),
+            "annyeong haseyo"
+        );
+        assert_eq!(
+            Text::from("같이 가요").pronounce().to_string(),
+            "가치 가요"
+        );
+    }
+}